@@ -230,7 +230,14 @@
         ];
 
         THEMES_PATH = "${onehalf}/sublimetext";
+        SYNTAXES_PATH = "${./saffi-wtf/syntaxes}";
         STATIC_PATH = packages.saffi-wtf-static;
+
+        # Image variants are generated at runtime, so they need somewhere
+        # writable rather than the store.
+        shellHook = ''
+          export IMAGE_CACHE_PATH="$PWD/.image-cache"
+        '';
       };
 
       formatter = pkgs.nixpkgs-fmt;
@@ -2,7 +2,9 @@ use std::{
     env::{self, VarError},
     fs::File,
     io::{self, Write},
-    path::PathBuf,
+    path::{Path, PathBuf},
+    process::Command,
+    time::{SystemTime, UNIX_EPOCH},
 };
 
 use grass::{Options, OutputStyle};
@@ -13,6 +15,8 @@ fn main() {
     if let Err(error) = compile_stylesheet() {
         panic!("{error}");
     }
+
+    capture_build_info();
 }
 
 #[derive(Debug, Error)]
@@ -48,3 +52,57 @@ fn compile_stylesheet() -> Result<(), CompileStylesheetError> {
     write!(stylesheet, "{}", compiled_css)
         .map_err(|err| CompileStylesheetError::WriteCss(out_path, err))
 }
+
+/// The `SOURCE_DATE_EPOCH` that Nix sets for builds that don't set their own,
+/// which is the start of 1980 rather than anything to do with the build.
+const NIX_DEFAULT_SOURCE_DATE_EPOCH: &str = "315532800";
+
+/// Captures the git commit and build time, for the `/version` endpoint.
+///
+/// Builds without a git checkout (like Nix builds) can set `GIT_COMMIT` and
+/// `SOURCE_DATE_EPOCH` themselves; otherwise the commit is reported as
+/// unknown. If the build time is only Nix's default, the time of the commit
+/// is used instead, or no build time at all if that can't be found.
+fn capture_build_info() {
+    println!("cargo:rerun-if-env-changed=GIT_COMMIT");
+    println!("cargo:rerun-if-env-changed=SOURCE_DATE_EPOCH");
+    for git_path in ["../.git/HEAD", "../.git/refs/heads"] {
+        if Path::new(git_path).exists() {
+            println!("cargo:rerun-if-changed={git_path}");
+        }
+    }
+
+    let git_commit = env::var("GIT_COMMIT")
+        .ok()
+        .or_else(|| git(&["rev-parse", "HEAD"]));
+    println!(
+        "cargo:rustc-env=GIT_COMMIT={}",
+        git_commit.as_deref().unwrap_or("unknown")
+    );
+
+    let build_timestamp = match env::var("SOURCE_DATE_EPOCH") {
+        Ok(epoch) if epoch == NIX_DEFAULT_SOURCE_DATE_EPOCH => {
+            git(&["log", "-1", "--format=%ct"]).and_then(|epoch| epoch.parse::<u64>().ok())
+        }
+        Ok(epoch) => epoch.parse().ok(),
+        Err(_) => SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .ok()
+            .map(|since_epoch| since_epoch.as_secs()),
+    };
+    println!(
+        "cargo:rustc-env=BUILD_TIMESTAMP={}",
+        build_timestamp.map_or_else(String::new, |timestamp| timestamp.to_string())
+    );
+}
+
+/// Runs a git command, returning its trimmed output if it succeeds.
+fn git(args: &[&str]) -> Option<String> {
+    Command::new("git")
+        .args(args)
+        .output()
+        .ok()
+        .filter(|output| output.status.success())
+        .and_then(|output| String::from_utf8(output.stdout).ok())
+        .map(|output| output.trim().to_owned())
+}
@@ -1,24 +1,84 @@
+use std::{error::Error as StdError, fmt, sync::Arc, time::Duration};
+
 use axum::{
     body::Body,
     extract::State,
-    http::{Request, StatusCode},
+    http::{header, HeaderMap, Request, StatusCode},
     middleware::Next,
     response::{IntoResponse, Response},
 };
 use thiserror::Error;
+use tracing::error;
+use uuid::Uuid;
 
-use crate::{state::Theme, templates::pages};
+use crate::{
+    layers::access_log::RequestId,
+    metrics::SiteMetrics,
+    state::{Content, ErrorPageKind, Theme},
+    templates::pages,
+};
 
 /// Errors that can be returned by request handlers.
 #[derive(Error, Clone, Debug)]
 pub enum HandlerError {
+    /// The request couldn't be understood, e.g. because its path couldn't be
+    /// decoded.
+    #[error("bad request")]
+    BadRequest,
+
+    /// The requested page needs credentials that weren't given.
+    #[error("unauthorized")]
+    Unauthorized,
+
     /// The requested page was not found.
     #[error("page not found")]
     NotFound,
 
+    /// The requested page exists, but not for the request's method.
+    #[error("method not allowed")]
+    MethodNotAllowed,
+
+    /// The requested page used to exist, but has been deliberately removed.
+    #[error("page gone")]
+    Gone,
+
     /// An internal server error occurred while trying to handle the request.
-    #[error("internal server error")]
-    InternalError,
+    #[error("internal server error: {0}")]
+    InternalError(#[source] Arc<dyn StdError + Send + Sync>),
+
+    /// Handling the request took longer than the configured timeout.
+    #[error("request timed out after {0:?}")]
+    Timeout(Duration),
+}
+
+impl HandlerError {
+    /// Wraps an error (or just a message) as an internal server error.
+    pub fn internal(error: impl Into<Box<dyn StdError + Send + Sync>>) -> Self {
+        Self::InternalError(Arc::from(error.into()))
+    }
+
+    /// Works out which error a response from something that doesn't return
+    /// `HandlerError` (like axum's extractors and router, or `ServeDir`)
+    /// stands for, if it's a bare error response rather than a page.
+    fn from_bare_response(response: &Response) -> Option<Self> {
+        let is_page = response
+            .headers()
+            .get(header::CONTENT_TYPE)
+            .and_then(|content_type| content_type.to_str().ok())
+            .is_some_and(|content_type| content_type.starts_with("text/html"));
+        if is_page {
+            return None;
+        }
+
+        match response.status() {
+            StatusCode::BAD_REQUEST => Some(Self::BadRequest),
+            StatusCode::UNAUTHORIZED => Some(Self::Unauthorized),
+            StatusCode::NOT_FOUND => Some(Self::NotFound),
+            StatusCode::METHOD_NOT_ALLOWED => Some(Self::MethodNotAllowed),
+            StatusCode::GONE => Some(Self::Gone),
+            _ => None,
+        }
+    }
 }
 
 /// `HandlerError` does implement [`IntoResponse`], so it can be returned from
@@ -37,31 +97,128 @@ impl IntoResponse for HandlerError {
     }
 }
 
+/// A short ID for an error, shown to readers on the error page so that they
+/// can quote it, and logged alongside the error so that it can be found.
+///
+/// It's the start of the request ID, so it also leads to the rest of the
+/// request's logs.
+#[derive(Clone, Debug)]
+pub struct ErrorId(String);
+
+impl ErrorId {
+    fn for_request(request_id: Option<&RequestId>) -> Self {
+        let request_id = request_id.map_or_else(Uuid::new_v4, |RequestId(id)| *id);
+        let mut id = request_id.simple().to_string();
+        id.truncate(8);
+        Self(id)
+    }
+}
+
+impl fmt::Display for ErrorId {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(&self.0)
+    }
+}
+
+/// Headers from the original response that still apply to the rendered error
+/// page.
+const KEPT_HEADERS: [header::HeaderName; 2] = [header::ALLOW, header::WWW_AUTHENTICATE];
+
 /// Renders errors returned from handlers etc. by extracting the error value
 /// from the extensions of the response.
 ///
-/// This is done so that state can be accessed when rendering errors.
+/// This is done so that state can be accessed when rendering errors. Bare
+/// error responses from things that don't know about `HandlerError` get
+/// rendered the same way. Error pages authored in content are used instead
+/// of the built-in ones where they exist.
 pub async fn render_error(
+    State(content): State<Content>,
     State(theme): State<Theme>,
+    State(metrics): State<SiteMetrics>,
     request: Request<Body>,
     next: Next,
 ) -> Response {
+    let error_id = ErrorId::for_request(request.extensions().get::<RequestId>());
+    let path = request.uri().path().to_owned();
     let mut response = next.run(request).await;
 
-    if let Some(handler_error) = response.extensions_mut().remove::<HandlerError>() {
-        match handler_error {
-            HandlerError::NotFound => {
-                let mut response = pages::not_found(theme).await.into_response();
-                *response.status_mut() = StatusCode::NOT_FOUND;
-                response
-            }
-            HandlerError::InternalError => {
-                let mut response = pages::internal_error(theme).await.into_response();
-                *response.status_mut() = StatusCode::INTERNAL_SERVER_ERROR;
-                response
-            }
+    let Some(handler_error) = response
+        .extensions_mut()
+        .remove::<HandlerError>()
+        .or_else(|| HandlerError::from_bare_response(&response))
+    else {
+        return response;
+    };
+
+    let (status, page) = match &handler_error {
+        HandlerError::BadRequest => (
+            StatusCode::BAD_REQUEST,
+            metrics
+                .time_render("bad_request", pages::bad_request(theme))
+                .await,
+        ),
+        HandlerError::Unauthorized => (
+            StatusCode::UNAUTHORIZED,
+            metrics
+                .time_render("unauthorized", pages::unauthorized(theme))
+                .await,
+        ),
+        HandlerError::NotFound => {
+            let suggestions = content.suggestions(&path);
+            metrics.record_not_found(&path, suggestions.first().copied());
+            (
+                StatusCode::NOT_FOUND,
+                metrics
+                    .time_render(
+                        "not_found",
+                        pages::not_found(
+                            theme,
+                            content.error_page(ErrorPageKind::NotFound, &path),
+                            &suggestions,
+                        ),
+                    )
+                    .await,
+            )
+        }
+        HandlerError::MethodNotAllowed => (
+            StatusCode::METHOD_NOT_ALLOWED,
+            metrics
+                .time_render("method_not_allowed", pages::method_not_allowed(theme))
+                .await,
+        ),
+        HandlerError::Gone => (
+            StatusCode::GONE,
+            metrics.time_render("gone", pages::gone(theme)).await,
+        ),
+        HandlerError::InternalError(_) | HandlerError::Timeout(_) => {
+            error!(%error_id, error = %handler_error, "failed to handle request");
+            let status = if let HandlerError::Timeout(_) = handler_error {
+                StatusCode::SERVICE_UNAVAILABLE
+            } else {
+                StatusCode::INTERNAL_SERVER_ERROR
+            };
+            (
+                status,
+                metrics
+                    .time_render(
+                        "internal_error",
+                        pages::internal_error(
+                            theme,
+                            content.error_page(ErrorPageKind::InternalError, &path),
+                            &error_id,
+                        ),
+                    )
+                    .await,
+            )
+        }
+    };
+
+    let mut kept_headers = HeaderMap::new();
+    for name in KEPT_HEADERS {
+        if let Some(value) = response.headers().get(&name) {
+            kept_headers.insert(name, value.clone());
         }
-    } else {
-        response
     }
+
+    (status, kept_headers, page).into_response()
 }
@@ -1,62 +1,233 @@
 use axum::{
     body::Body,
     extract::{Path, State},
-    http::{header, Request, Response},
+    http::{header, HeaderMap, Request, Response},
+    response::{IntoResponse, Response as AxumResponse},
 };
 use maud::Markup;
-use tracing::{info, warn};
+use tracing::warn;
 
 use crate::{
     errors::HandlerError,
-    state::{names::GroupName, Content, Theme},
+    metrics::SiteMetrics,
+    state::{
+        canonical::Canonical,
+        names::GroupName,
+        render::{GroupRef, PostRef},
+        Content, Theme, STYLESHEET,
+    },
     templates::pages,
 };
 
-const STYLESHEET: &str = include_str!(concat!(env!("OUT_DIR"), "/style.css"));
+/// The name a group's page is given in the URL of its alternate formats, for
+/// the root group, which has no name of its own.
+const ROOT_ALTERNATE_NAME: &str = "index";
+
+/// The formats pages can be served in.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+enum Format {
+    Html,
+    /// The original markdown, with frontmatter.
+    Markdown,
+    /// A plain-text rendering, for `curl`-style clients.
+    Text,
+}
+
+impl Format {
+    /// Splits the format suffix (`.md` or `.txt`) off the name of a page in a
+    /// path, falling back to the format the `Accept` header asks for if there
+    /// isn't one.
+    fn from_name<'a>(name: &'a str, headers: &HeaderMap) -> (&'a str, Self) {
+        if let Some(name) = name.strip_suffix(".md") {
+            (name, Self::Markdown)
+        } else if let Some(name) = name.strip_suffix(".txt") {
+            (name, Self::Text)
+        } else {
+            (name, Self::from_accept(headers))
+        }
+    }
+
+    /// Picks HTML or plain text, whichever the client prefers by the quality
+    /// values in its `Accept` header, going by the most specific media range
+    /// that matches each one. HTML wins ties, and is used if neither is
+    /// acceptable. Markdown is only served with the `.md` suffix, since not
+    /// every page has any.
+    fn from_accept(headers: &HeaderMap) -> Self {
+        let ranges = headers
+            .get_all(header::ACCEPT)
+            .iter()
+            .filter_map(|value| value.to_str().ok())
+            .flat_map(|value| value.split(','))
+            .map(|range| {
+                let mut params = range.split(';');
+                let media_range = params
+                    .next()
+                    .unwrap_or_default()
+                    .trim()
+                    .to_ascii_lowercase();
+                let quality = params
+                    .filter_map(|param| param.split_once('='))
+                    .find(|(name, _)| name.trim().eq_ignore_ascii_case("q"))
+                    .and_then(|(_, quality)| quality.trim().parse::<f32>().ok())
+                    .unwrap_or(1.0);
+                (media_range, quality)
+            })
+            .collect::<Vec<_>>();
+
+        let quality = |media_type: &str| {
+            let (kind, _) = media_type.split_once('/').unwrap_or_default();
+            ranges
+                .iter()
+                .filter_map(|(range, quality)| {
+                    let specificity = if range == media_type {
+                        2
+                    } else if range.strip_suffix("/*") == Some(kind) {
+                        1
+                    } else if range == "*/*" {
+                        0
+                    } else {
+                        return None;
+                    };
+                    Some((specificity, *quality))
+                })
+                .max_by_key(|(specificity, _)| *specificity)
+                .map_or(0.0, |(_, quality)| quality)
+        };
+
+        let text_quality = quality("text/plain");
+        if text_quality > 0.0 && text_quality > quality("text/html") {
+            Self::Text
+        } else {
+            Self::Html
+        }
+    }
+}
+
+/// Serves a page as HTML. The same URL can serve plain text to clients that
+/// ask for it, so caches need to know it depends on `Accept`.
+fn html_response(page: Markup) -> AxumResponse {
+    ([(header::VARY, "Accept")], page).into_response()
+}
+
+fn markdown_response(raw_content: &str) -> AxumResponse {
+    (
+        [(header::CONTENT_TYPE, "text/markdown; charset=utf-8")],
+        raw_content.to_owned(),
+    )
+        .into_response()
+}
+
+fn text_response(text: String) -> AxumResponse {
+    (
+        [
+            (header::CONTENT_TYPE, "text/plain; charset=utf-8"),
+            (header::VARY, "Accept"),
+        ],
+        text,
+    )
+        .into_response()
+}
 
 pub async fn index(
     State(content): State<Content>,
     State(theme): State<Theme>,
+    State(canonical): State<Canonical>,
+    State(metrics): State<SiteMetrics>,
     request: Request<Body>,
-) -> Result<Markup, HandlerError> {
-    info!(route = %request.uri(), "handling request");
+) -> Result<AxumResponse, HandlerError> {
+    let format = Format::from_accept(request.headers());
 
-    if let Some(group) = content.group(&GroupName::Root) {
-        Ok(pages::group(group, theme).await)
-    } else {
-        Err(not_found(request).await)
+    let response = match content.group(&GroupName::Root) {
+        Some(group) => group_response(group, format, theme, canonical.url("/"), &metrics).await,
+        None => None,
+    };
+    match response {
+        Some(response) => Ok(response),
+        None => Err(not_found(request).await),
     }
 }
 
 pub async fn group(
     State(content): State<Content>,
     State(theme): State<Theme>,
+    State(canonical): State<Canonical>,
+    State(metrics): State<SiteMetrics>,
     Path(group): Path<String>,
     request: Request<Body>,
-) -> Result<Markup, HandlerError> {
-    info!(route = %request.uri(), "handling request");
+) -> Result<AxumResponse, HandlerError> {
+    let (name, format) = Format::from_name(&group, request.headers());
+    let has_suffix = name.len() < group.len();
 
-    if let Some(page) = group
-        .try_into()
+    // The root group's alternates are at `/index.md` and `/index.txt`, unless
+    // there's a group that's actually called that.
+    let page = GroupName::try_from(name.to_owned())
         .ok()
         .and_then(|group| content.group(&group))
-    {
-        Ok(pages::group(page, theme).await)
-    } else {
-        Err(not_found(request).await)
+        .or_else(|| {
+            (has_suffix && name == ROOT_ALTERNATE_NAME)
+                .then(|| content.group(&GroupName::Root))
+                .flatten()
+        });
+
+    let response = match page {
+        Some(page) => {
+            let canonical_url = canonical.url(&page.name.route());
+            group_response(page, format, theme, canonical_url, &metrics).await
+        }
+        // Posts in the root group are served at the top level.
+        None => match name.to_owned().try_into() {
+            Ok(post_name) => match content.post(&GroupName::Root, &post_name) {
+                Some(post) => Some(post_response(post, format, theme, &canonical, &metrics).await),
+                None => None,
+            },
+            Err(_) => None,
+        },
+    };
+    match response {
+        Some(response) => Ok(response),
+        None => Err(not_found(request).await),
+    }
+}
+
+/// Serves a group's page in the given format. Only groups with an index page
+/// have any markdown to serve.
+async fn group_response(
+    group: GroupRef<'_>,
+    format: Format,
+    theme: Theme,
+    canonical_url: String,
+    metrics: &SiteMetrics,
+) -> Option<AxumResponse> {
+    match format {
+        Format::Html => Some(html_response(
+            metrics
+                .time_render("group", pages::group(group, theme, canonical_url))
+                .await,
+        )),
+        Format::Markdown => group
+            .index_page()
+            .map(|page| markdown_response(&page.raw_content)),
+        Format::Text => Some(text_response(group.text())),
     }
 }
 
 pub async fn tagged(
     State(content): State<Content>,
     State(theme): State<Theme>,
+    State(canonical): State<Canonical>,
+    State(metrics): State<SiteMetrics>,
     Path(tag): Path<String>,
     request: Request<Body>,
 ) -> Result<Markup, HandlerError> {
-    info!(route = %request.uri(), "handling request");
-
-    if let Some(page) = tag.try_into().ok().and_then(|tag| content.tag(&tag)) {
-        Ok(pages::tagged(page, theme).await)
+    if let Some((tag, page)) = tag
+        .try_into()
+        .ok()
+        .and_then(|tag| content.tag(&tag).map(|page| (tag, page)))
+    {
+        let canonical_url = canonical.url(&format!("/tagged/{tag}"));
+        Ok(metrics
+            .time_render("tagged", pages::tagged(page, theme, canonical_url))
+            .await)
     } else {
         Err(not_found(request).await)
     }
@@ -65,11 +236,12 @@ pub async fn tagged(
 pub async fn post(
     State(content): State<Content>,
     State(theme): State<Theme>,
+    State(canonical): State<Canonical>,
+    State(metrics): State<SiteMetrics>,
     Path((group, post)): Path<(String, String)>,
     request: Request<Body>,
-) -> Result<Markup, HandlerError> {
-    info!(route = %request.uri(), "handling request");
-
+) -> Result<AxumResponse, HandlerError> {
+    let (post, format) = Format::from_name(&post, request.headers());
     let group = group.try_into().ok();
     let post = post.try_into().ok();
 
@@ -77,18 +249,39 @@ pub async fn post(
         .zip(post)
         .and_then(|(group, post)| content.post(&group, &post))
     {
-        Ok(pages::post(post, theme).await)
+        Ok(post_response(post, format, theme, &canonical, &metrics).await)
     } else {
         Err(not_found(request).await)
     }
 }
 
-pub async fn stylesheet(request: Request<Body>) -> Result<Response<String>, HandlerError> {
-    info!(route = %request.uri(), "handling request");
+/// Serves a post in the given format.
+async fn post_response(
+    post: PostRef<'_>,
+    format: Format,
+    theme: Theme,
+    canonical: &Canonical,
+    metrics: &SiteMetrics,
+) -> AxumResponse {
+    match format {
+        Format::Html => {
+            let canonical_url = canonical.url(&post.group_name.post_route(post.name));
+            html_response(
+                metrics
+                    .time_render("post", pages::post(post, theme, canonical_url))
+                    .await,
+            )
+        }
+        Format::Markdown => markdown_response(&post.post.raw_content),
+        Format::Text => text_response(post.post.text()),
+    }
+}
+
+pub async fn stylesheet() -> Result<Response<String>, HandlerError> {
     Response::builder()
         .header(header::CONTENT_TYPE, "text/css")
         .body(STYLESHEET.to_owned())
-        .map_err(|_| HandlerError::InternalError)
+        .map_err(HandlerError::internal)
 }
 
 pub async fn not_found(request: Request<Body>) -> HandlerError {
@@ -99,5 +292,30 @@ pub async fn not_found(request: Request<Body>) -> HandlerError {
 #[cfg(debug_assertions)]
 pub async fn internal_error(request: Request<Body>) -> HandlerError {
     warn!(route = %request.uri(), "internal error page explicitly requested");
-    HandlerError::InternalError
+    HandlerError::internal("internal error page explicitly requested")
+}
+
+#[cfg(test)]
+mod tests {
+    use axum::http::HeaderValue;
+
+    use super::*;
+
+    fn format_for(accept: &'static str) -> Format {
+        let mut headers = HeaderMap::new();
+        headers.insert(header::ACCEPT, HeaderValue::from_static(accept));
+        Format::from_accept(&headers)
+    }
+
+    #[test]
+    fn picks_the_preferred_format() {
+        assert_eq!(Format::from_accept(&HeaderMap::new()), Format::Html);
+        assert_eq!(format_for("*/*"), Format::Html);
+        assert_eq!(format_for("text/plain"), Format::Text);
+        assert_eq!(format_for("text/plain, text/*;q=0.1"), Format::Text);
+        assert_eq!(format_for("text/plain;q=0, text/html"), Format::Html);
+        assert_eq!(format_for("text/html;q=0.1, text/plain"), Format::Text);
+        assert_eq!(format_for("text/*;q=0.5, text/html;q=0"), Format::Text);
+        assert_eq!(format_for("image/png"), Format::Html);
+    }
 }
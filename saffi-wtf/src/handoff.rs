@@ -0,0 +1,202 @@
+use std::{
+    env,
+    io::{self, Write},
+    os::{
+        fd::{AsRawFd, FromRawFd, OwnedFd, RawFd},
+        unix::net::UnixStream as StdUnixStream,
+    },
+    process::ExitStatus,
+    time::Duration,
+};
+
+use thiserror::Error;
+use tokio::{io::AsyncReadExt, net::UnixStream, process::Command, time};
+use tracing::{info, warn};
+
+/// The env var that tells a new process which file descriptor to report its
+/// readiness on.
+const READY_FD_ENV: &str = "HANDOFF_READY_FD";
+
+/// The first file descriptor inherited sockets are passed in, as in the
+/// systemd socket activation protocol.
+const FIRST_LISTEN_FD: RawFd = 3;
+
+/// What a new process writes to report that it's ready to serve.
+const READY_MESSAGE: &[u8] = b"ready\n";
+
+/// How long a new process gets to load its content and report that it's
+/// ready.
+const READY_TIMEOUT: Duration = Duration::from_secs(120);
+
+/// Starts a new instance of this binary, with the same arguments, handing it
+/// the given listening sockets, and waits for it to report that it's ready to
+/// serve.
+///
+/// The sockets are passed with the systemd socket activation protocol, in
+/// the order given, so the new process picks them up the same way it would
+/// from systemd. Both processes accept connections until this one shuts
+/// down, so none are refused in between.
+///
+/// This doesn't work under systemd, which only knows about this process as
+/// the service's main PID. Once it exits, systemd considers the service
+/// stopped (with `Type=simple`, `exec` or `notify` alike), and by default
+/// kills the rest of its processes, including the new one. There, restart
+/// the service instead, with socket activation so that connections wait in
+/// the socket's backlog while it starts rather than being refused.
+pub async fn upgrade(listen_fds: &[RawFd]) -> Result<(), HandoffError> {
+    use HandoffError::*;
+
+    // The binary might have been replaced since this process started, so use
+    // the path it was started with rather than the one it's running from.
+    let program = env::args_os()
+        .next()
+        .map(Ok)
+        .unwrap_or_else(|| env::current_exe().map(Into::into))
+        .map_err(Spawn)?;
+
+    let (ready_rx, ready_tx) = StdUnixStream::pair().map_err(ReadyPipe)?;
+    let ready_fd = FIRST_LISTEN_FD + RawFd::try_from(listen_fds.len()).unwrap_or(RawFd::MAX);
+
+    // Every fd to pass on first gets duplicated somewhere above the range it
+    // needs to end up in, so that moving one into place can't clobber
+    // another that hasn't been moved yet.
+    let to_pass = listen_fds
+        .iter()
+        .copied()
+        .chain([ready_tx.as_raw_fd()])
+        .map(|fd| duplicate_above(fd, ready_fd + 1))
+        .collect::<io::Result<Vec<_>>>()
+        .map_err(DuplicateFd)?;
+    drop(ready_tx);
+
+    let mut command = Command::new(program);
+    command
+        .args(env::args_os().skip(1))
+        .env("LISTEN_FDS", listen_fds.len().to_string())
+        .env("LISTEN_FDS_FIRST_FD", FIRST_LISTEN_FD.to_string())
+        .env_remove("LISTEN_PID")
+        .env(READY_FD_ENV, ready_fd.to_string());
+
+    let raw_to_pass = to_pass.iter().map(AsRawFd::as_raw_fd).collect::<Vec<_>>();
+    // SAFETY: this only calls `dup2`, which is async-signal-safe, so it's
+    // fine to call between forking and exec-ing. The duplicates it creates
+    // don't have `FD_CLOEXEC` set, so they survive into the new process.
+    unsafe {
+        command.pre_exec(move || {
+            for (target, &fd) in (FIRST_LISTEN_FD..).zip(&raw_to_pass) {
+                if libc::dup2(fd, target) == -1 {
+                    return Err(io::Error::last_os_error());
+                }
+            }
+            Ok(())
+        });
+    }
+
+    let mut child = command.spawn().map_err(Spawn)?;
+    drop(to_pass);
+    info!(
+        pid = child.id(),
+        "started new process, waiting for it to be ready"
+    );
+
+    ready_rx.set_nonblocking(true).map_err(ReadyPipe)?;
+    let mut ready_rx = UnixStream::from_std(ready_rx).map_err(ReadyPipe)?;
+    let mut message = Vec::new();
+
+    let ready = async {
+        // The new process closes its end once it's reported that it's ready,
+        // or when it exits.
+        ready_rx
+            .read_to_end(&mut message)
+            .await
+            .map_err(ReadyPipe)?;
+        if message == READY_MESSAGE {
+            Ok(())
+        } else {
+            Err(NotReady)
+        }
+    };
+
+    let result = tokio::select! {
+        result = time::timeout(READY_TIMEOUT, ready) => result.unwrap_or(Err(Timeout)),
+        status = child.wait() => Err(status.map_or_else(Spawn, ChildExited)),
+    };
+
+    match result {
+        Ok(()) => info!(pid = child.id(), "new process is ready"),
+        // The new process is sharing the listening sockets, so it mustn't be
+        // left running if it isn't going to take over.
+        Err(_) => {
+            if let Err(error) = child.kill().await {
+                warn!(%error, "failed to kill new process");
+            }
+        }
+    }
+    result
+}
+
+/// The socket to tell the process that started this one, with [`upgrade()`],
+/// that this one is ready to serve.
+#[derive(Debug)]
+pub struct ReadyNotifier(StdUnixStream);
+
+impl ReadyNotifier {
+    /// Takes the socket to report readiness on, if this process was started
+    /// by [`upgrade()`], and removes it from the environment so that anything
+    /// this process starts doesn't think it's been handed off to.
+    ///
+    /// Changing the environment while another thread might be reading it is
+    /// a data race, so this has to be called before any threads are started.
+    pub fn from_env() -> Option<Self> {
+        let fd = env::var(READY_FD_ENV)
+            .ok()
+            .and_then(|fd| fd.parse::<RawFd>().ok());
+        env::remove_var(READY_FD_ENV);
+
+        // SAFETY: the old process put the ready socket at this fd, and nothing
+        // else in this process knows about it, so it's safe to take ownership.
+        fd.map(|fd| Self(unsafe { StdUnixStream::from_raw_fd(fd) }))
+    }
+
+    /// Tells the old process that this one is ready to serve.
+    pub fn notify(mut self) {
+        match self.0.write_all(READY_MESSAGE) {
+            Ok(()) => info!("told old process that this one is ready"),
+            Err(error) => warn!(%error, "failed to tell old process that this one is ready"),
+        }
+    }
+}
+
+/// Duplicates `fd` to the lowest free fd at or above `min`, with
+/// `FD_CLOEXEC` set so that it isn't leaked into other processes.
+fn duplicate_above(fd: RawFd, min: RawFd) -> io::Result<OwnedFd> {
+    // SAFETY: `fcntl` with `F_DUPFD_CLOEXEC` doesn't touch any memory, and
+    // returns either an error or a new fd that nothing else owns.
+    let duplicate = unsafe { libc::fcntl(fd, libc::F_DUPFD_CLOEXEC, min) };
+    if duplicate == -1 {
+        return Err(io::Error::last_os_error());
+    }
+    // SAFETY: the new fd was just created, so this is its only owner.
+    Ok(unsafe { OwnedFd::from_raw_fd(duplicate) })
+}
+
+#[derive(Error, Debug)]
+pub enum HandoffError {
+    #[error("failed to start new process: {0}")]
+    Spawn(#[source] io::Error),
+
+    #[error("failed to duplicate listening socket: {0}")]
+    DuplicateFd(#[source] io::Error),
+
+    #[error("failed to set up socket for readiness reports: {0}")]
+    ReadyPipe(#[source] io::Error),
+
+    #[error("new process exited before it was ready: {0}")]
+    ChildExited(ExitStatus),
+
+    #[error("new process stopped without reporting that it was ready")]
+    NotReady,
+
+    #[error("new process didn't report that it was ready in time")]
+    Timeout,
+}
@@ -0,0 +1,113 @@
+use std::sync::{Arc, RwLock};
+
+use axum::{extract::State, http::StatusCode, routing::get, Json, Router};
+use chrono::{DateTime, Utc};
+use serde::Serialize;
+
+const GIT_COMMIT: &str = env!("GIT_COMMIT");
+/// Seconds since the Unix epoch, or empty if the build time isn't known.
+const BUILD_TIMESTAMP: &str = env!("BUILD_TIMESTAMP");
+
+/// Tracks whether the content has been loaded, for the readiness endpoint.
+#[derive(Clone, Debug, Default)]
+pub struct Health {
+    loads: Arc<RwLock<Loads>>,
+}
+
+#[derive(Clone, Debug, Default, Serialize)]
+struct Loads {
+    last_load: Option<LoadOutcome>,
+    last_success_at: Option<DateTime<Utc>>,
+}
+
+#[derive(Clone, Debug, Serialize)]
+struct LoadOutcome {
+    finished_at: DateTime<Utc>,
+    error: Option<String>,
+}
+
+impl Health {
+    /// Records that the content for every site was loaded successfully.
+    pub fn loaded(&self) {
+        self.record(None);
+    }
+
+    /// Records that loading the content failed, leaving the content from the
+    /// last successful load (if there was one) being served.
+    pub fn failed(&self, error: String) {
+        self.record(Some(error));
+    }
+
+    fn record(&self, error: Option<String>) {
+        let finished_at = Utc::now();
+        let mut loads = self.loads.write().expect("lock should not be poisoned");
+        if error.is_none() {
+            loads.last_success_at = Some(finished_at);
+        }
+        loads.last_load = Some(LoadOutcome { finished_at, error });
+    }
+}
+
+/// Builds the routes for the health, readiness and version endpoints.
+///
+/// These are served for every host, ahead of the sites themselves, so that
+/// probes don't show up in request traces or logs.
+pub fn routes(health: Health) -> Router {
+    Router::new()
+        .route("/healthz", get(healthz))
+        .route("/readyz", get(readyz))
+        .route("/version", get(version))
+        .with_state(health)
+}
+
+/// Always succeeds, as long as the process is up and serving requests.
+async fn healthz() -> &'static str {
+    "ok"
+}
+
+#[derive(Serialize)]
+struct Readiness {
+    ready: bool,
+    #[serde(flatten)]
+    loads: Loads,
+}
+
+/// Succeeds if the last attempt to load the content succeeded. After a failed
+/// reload the old content is still served, but it isn't what was asked for,
+/// so this fails until a reload succeeds.
+async fn readyz(State(health): State<Health>) -> (StatusCode, Json<Readiness>) {
+    let loads = health
+        .loads
+        .read()
+        .expect("lock should not be poisoned")
+        .clone();
+    let ready = loads
+        .last_load
+        .as_ref()
+        .is_some_and(|last_load| last_load.error.is_none());
+
+    let status = if ready {
+        StatusCode::OK
+    } else {
+        StatusCode::SERVICE_UNAVAILABLE
+    };
+    (status, Json(Readiness { ready, loads }))
+}
+
+#[derive(Serialize)]
+struct Version {
+    version: &'static str,
+    git_commit: &'static str,
+    built_at: Option<DateTime<Utc>>,
+}
+
+async fn version() -> Json<Version> {
+    Json(Version {
+        version: env!("CARGO_PKG_VERSION"),
+        git_commit: GIT_COMMIT,
+        built_at: BUILD_TIMESTAMP
+            .parse()
+            .ok()
+            .and_then(|timestamp| DateTime::from_timestamp(timestamp, 0)),
+    })
+}
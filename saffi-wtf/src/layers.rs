@@ -0,0 +1,44 @@
+use axum::{
+    body::Body,
+    http::{header, Method, Request, StatusCode},
+    response::{IntoResponse, Response},
+};
+
+pub mod access_log;
+pub mod canonical;
+pub mod metrics;
+pub mod panics;
+pub mod redirects;
+pub mod security;
+pub mod timeout;
+
+/// Builds a permanent redirect to `location`.
+///
+/// `GET` and `HEAD` requests get a `301 Moved Permanently`; anything else gets
+/// a `308 Permanent Redirect`, so that clients keep the method and body.
+pub fn permanent_redirect(method: &Method, location: String) -> Response {
+    let status = if method == Method::GET || method == Method::HEAD {
+        StatusCode::MOVED_PERMANENTLY
+    } else {
+        StatusCode::PERMANENT_REDIRECT
+    };
+
+    (status, [(header::LOCATION, location)]).into_response()
+}
+
+/// Finds the host a request was sent to, including the port if there is one.
+///
+/// HTTP/1.1 requests carry the host in a header, but HTTP/2 requests carry it
+/// in the URI.
+pub fn request_host(request: &Request<Body>) -> Option<&str> {
+    request
+        .headers()
+        .get(header::HOST)
+        .and_then(|host| host.to_str().ok())
+        .or_else(|| {
+            request
+                .uri()
+                .authority()
+                .map(|authority| authority.as_str())
+        })
+}
@@ -0,0 +1,268 @@
+use std::{fmt::Write as _, net::SocketAddr, time::Instant};
+
+use axum::{
+    body::{Body, HttpBody},
+    extract::{ConnectInfo, State},
+    http::{header, HeaderName, HeaderValue, Request},
+    middleware::Next,
+    response::Response,
+};
+use camino::Utf8PathBuf;
+use chrono::{DateTime, Local};
+use prometheus_client::metrics::counter::Counter;
+use tokio::{
+    fs::OpenOptions,
+    io::{self, AsyncWriteExt, BufWriter},
+    sync::mpsc::{self, error::TrySendError},
+};
+use tracing::{info, info_span, warn, Instrument};
+use uuid::Uuid;
+
+use crate::{layers::request_host, metrics::Metrics};
+
+/// How many lines can be waiting to be written to the access log file before
+/// any more are dropped.
+const MAX_PENDING_LINES: usize = 4096;
+
+/// The header carrying the ID generated for every request, so that a response
+/// can be matched up with its access log entry and traces.
+pub static X_REQUEST_ID: HeaderName = HeaderName::from_static("x-request-id");
+
+/// Options for the access log.
+#[derive(clap::Args, Clone, Debug)]
+pub struct AccessLogArgs {
+    /// A file to append access log lines to, as well as logging them with
+    /// everything else.
+    #[arg(long, env = "ACCESS_LOG")]
+    pub access_log: Option<Utf8PathBuf>,
+
+    #[arg(long, env = "ACCESS_LOG_FORMAT", value_enum, default_value_t)]
+    pub access_log_format: AccessLogFormat,
+}
+
+/// The format of lines written to the access log file.
+#[derive(clap::ValueEnum, Clone, Copy, Debug, Default)]
+pub enum AccessLogFormat {
+    /// The Common Log Format.
+    Common,
+    /// The Combined Log Format, which adds the referrer and user agent to the
+    /// Common Log Format.
+    #[default]
+    Combined,
+}
+
+/// The ID generated for a request, added to its extensions.
+#[derive(Clone, Copy, Debug)]
+pub struct RequestId(pub Uuid);
+
+/// Where access log lines get written, other than the usual logs.
+///
+/// Lines are handed off to a task that appends them to the file, so that
+/// requests never wait on it. If the file can't keep up, lines are dropped
+/// and counted rather than queueing up without limit.
+#[derive(Clone, Debug, Default)]
+pub struct AccessLog {
+    file: Option<AccessLogFile>,
+}
+
+#[derive(Clone, Debug)]
+struct AccessLogFile {
+    format: AccessLogFormat,
+    lines_tx: mpsc::Sender<String>,
+    dropped: Counter,
+}
+
+impl AccessLog {
+    pub async fn open(args: &AccessLogArgs, metrics: &Metrics) -> io::Result<Self> {
+        let Some(path) = &args.access_log else {
+            return Ok(Self::default());
+        };
+
+        let file = OpenOptions::new()
+            .create(true)
+            .append(true)
+            .open(path)
+            .await?;
+        let (lines_tx, lines_rx) = mpsc::channel(MAX_PENDING_LINES);
+        tokio::spawn(write_lines(path.clone(), BufWriter::new(file), lines_rx));
+
+        Ok(Self {
+            file: Some(AccessLogFile {
+                format: args.access_log_format,
+                lines_tx,
+                dropped: metrics.access_log_dropped(),
+            }),
+        })
+    }
+}
+
+async fn write_lines(
+    path: Utf8PathBuf,
+    mut file: BufWriter<tokio::fs::File>,
+    mut lines_rx: mpsc::Receiver<String>,
+) {
+    while let Some(line) = lines_rx.recv().await {
+        let written = async {
+            file.write_all(line.as_bytes()).await?;
+            // Only flush once there's nothing else waiting to be written.
+            while let Ok(line) = lines_rx.try_recv() {
+                file.write_all(line.as_bytes()).await?;
+            }
+            file.flush().await
+        };
+        if let Err(error) = written.await {
+            warn!(%path, %error, "failed to write to access log");
+        }
+    }
+}
+
+/// Logs every request once its response is ready, with its status, latency,
+/// referrer and user agent.
+///
+/// Every request gets a generated ID, which is recorded on a span wrapping
+/// everything else that happens while handling it, and returned in the
+/// `X-Request-Id` header.
+pub async fn log_request(
+    State(access_log): State<AccessLog>,
+    mut request: Request<Body>,
+    next: Next,
+) -> Response {
+    let request_id = Uuid::new_v4();
+    request.extensions_mut().insert(RequestId(request_id));
+
+    let entry = Entry::from_request(&request);
+    let span = info_span!(
+        "request",
+        request_id = %request_id,
+        method = %entry.method,
+        uri = %entry.uri,
+    );
+    let start = Instant::now();
+
+    let mut response = next.run(request).instrument(span.clone()).await;
+
+    let latency = start.elapsed();
+    let status = response.status();
+    // Most bodies know their exact size up front, even when nothing has set
+    // `Content-Length` yet, because that's left to hyper.
+    let bytes = response.body().size_hint().exact().or_else(|| {
+        response
+            .headers()
+            .get(header::CONTENT_LENGTH)
+            .and_then(|length| length.to_str().ok())
+            .and_then(|length| length.parse::<u64>().ok())
+    });
+
+    span.in_scope(|| {
+        info!(
+            status = status.as_u16(),
+            latency_ms = latency.as_secs_f64() * 1000.0,
+            bytes,
+            host = entry.host.as_deref(),
+            remote_addr = entry.remote_addr.map(tracing::field::display),
+            referrer = entry.referrer.as_deref(),
+            user_agent = entry.user_agent.as_deref(),
+            "handled request"
+        );
+    });
+
+    if let Some(file) = &access_log.file {
+        match file
+            .lines_tx
+            .try_send(entry.format(file.format, status.as_u16(), bytes))
+        {
+            Ok(()) => {}
+            Err(TrySendError::Full(_)) => {
+                file.dropped.inc();
+            }
+            // The receiving task only stops if writing to the file is
+            // impossible, and it's already logged that.
+            Err(TrySendError::Closed(_)) => {}
+        }
+    }
+
+    if let Ok(value) = HeaderValue::from_str(&request_id.to_string()) {
+        response.headers_mut().insert(X_REQUEST_ID.clone(), value);
+    }
+    response
+}
+
+/// The parts of a request that go in the access log, taken before it's
+/// handed on.
+struct Entry {
+    received_at: DateTime<Local>,
+    remote_addr: Option<SocketAddr>,
+    method: String,
+    uri: String,
+    version: String,
+    host: Option<String>,
+    referrer: Option<String>,
+    user_agent: Option<String>,
+}
+
+impl Entry {
+    fn from_request(request: &Request<Body>) -> Self {
+        let header = |name| {
+            request
+                .headers()
+                .get(name)
+                .and_then(|value: &HeaderValue| value.to_str().ok())
+                .map(str::to_owned)
+        };
+
+        Self {
+            received_at: Local::now(),
+            remote_addr: request
+                .extensions()
+                .get::<ConnectInfo<SocketAddr>>()
+                .map(|ConnectInfo(addr)| *addr),
+            method: request.method().to_string(),
+            uri: request.uri().to_string(),
+            version: format!("{:?}", request.version()),
+            host: request_host(request).map(str::to_owned),
+            referrer: header(header::REFERER),
+            user_agent: header(header::USER_AGENT),
+        }
+    }
+
+    /// Formats this as a line in the Common or Combined Log Format.
+    fn format(&self, format: AccessLogFormat, status: u16, bytes: Option<u64>) -> String {
+        let mut line = String::new();
+        let _ = write!(
+            line,
+            "{} - - [{}] \"{} {} {}\" {status} ",
+            self.remote_addr
+                .map_or_else(|| "-".to_owned(), |addr| addr.ip().to_string()),
+            self.received_at.format("%d/%b/%Y:%H:%M:%S %z"),
+            self.method,
+            escape(&self.uri),
+            self.version,
+        );
+        match bytes {
+            Some(bytes) => {
+                let _ = write!(line, "{bytes}");
+            }
+            None => line.push('-'),
+        }
+
+        if let AccessLogFormat::Combined = format {
+            let _ = write!(
+                line,
+                " \"{}\" \"{}\"",
+                self.referrer.as_deref().map_or_else(|| "-".into(), escape),
+                self.user_agent
+                    .as_deref()
+                    .map_or_else(|| "-".into(), escape),
+            );
+        }
+
+        line.push('\n');
+        line
+    }
+}
+
+/// Escapes quotes, backslashes and control characters, so that a value can't
+/// break out of its quoted field or its line.
+fn escape(value: &str) -> String {
+    value.escape_default().to_string()
+}
@@ -0,0 +1,84 @@
+use axum::{body::Body, extract::State, http::Request, middleware::Next, response::Response};
+use tracing::info;
+
+use crate::{
+    layers::{permanent_redirect, request_host},
+    state::canonical::{canonical_path, Canonical},
+};
+
+/// Redirects requests to the canonical form of their URL, so that every page
+/// is only ever served from one URL.
+///
+/// Paths with a trailing slash are redirected to the same path without one,
+/// and if a canonical URL is configured, requests that arrive for any other
+/// host are redirected to it.
+pub async fn canonicalise(
+    State(canonical): State<Canonical>,
+    request: Request<Body>,
+    next: Next,
+) -> Response {
+    let uri = request.uri();
+    let path = canonical_path(uri.path());
+
+    let host = request_host(&request);
+    let wrong_host = canonical
+        .authority()
+        .zip(host)
+        .is_some_and(|(authority, host)| !authority.as_str().eq_ignore_ascii_case(host));
+
+    if !wrong_host && path == uri.path() {
+        return next.run(request).await;
+    }
+
+    let mut location = if wrong_host {
+        canonical.url(path)
+    } else {
+        path.to_owned()
+    };
+    if let Some(query) = uri.query() {
+        location.push('?');
+        location.push_str(query);
+    }
+
+    info!(route = %uri, %location, "redirecting request to canonical URL");
+    permanent_redirect(request.method(), location)
+}
+
+#[cfg(test)]
+mod tests {
+    use axum::{
+        body::Body,
+        http::{header, Request, StatusCode},
+        middleware,
+        routing::get,
+        Router,
+    };
+    use tower::ServiceExt as _;
+
+    use super::canonicalise;
+    use crate::state::canonical::Canonical;
+
+    async fn location(path: &str) -> Option<String> {
+        let app = Router::new().route("/evil.com", get(|| async {})).layer(
+            middleware::from_fn_with_state(Canonical::default(), canonicalise),
+        );
+
+        let response = app
+            .oneshot(Request::get(path).body(Body::empty()).unwrap())
+            .await
+            .unwrap();
+        assert_eq!(response.status(), StatusCode::MOVED_PERMANENTLY);
+
+        response
+            .headers()
+            .get(header::LOCATION)
+            .map(|location| location.to_str().unwrap().to_owned())
+    }
+
+    #[tokio::test]
+    async fn redirects_stay_on_the_same_host() {
+        assert_eq!(location("//evil.com/").await.as_deref(), Some("/evil.com"));
+        assert_eq!(location("///evil.com").await.as_deref(), Some("/evil.com"));
+        assert_eq!(location("//").await.as_deref(), Some("/"));
+    }
+}
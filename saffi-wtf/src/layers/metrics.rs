@@ -0,0 +1,68 @@
+use std::time::Instant;
+
+use axum::{
+    body::Body,
+    extract::{MatchedPath, State},
+    http::{Method, Request},
+    middleware::Next,
+    response::Response,
+};
+
+use crate::{metrics::SiteMetrics, state::images::IMAGES_PREFIX};
+
+/// The route label for requests that didn't match any route, so that every
+/// unknown URI doesn't get its own label.
+const UNMATCHED_ROUTE: &str = "fallback";
+
+/// The method label for requests with a method that isn't one of the standard
+/// ones, so that clients can't make up a new label with every request.
+const OTHER_METHOD: &str = "other";
+
+/// Records the count and duration of every request, labelled by the route
+/// template it matched rather than its URI.
+///
+/// Nested services don't report the path they matched, so requests for
+/// static files and images are labelled by their prefix instead.
+pub async fn record_request(
+    State(metrics): State<SiteMetrics>,
+    matched_path: Option<MatchedPath>,
+    request: Request<Body>,
+    next: Next,
+) -> Response {
+    let method = method_label(request.method());
+    let path = request.uri().path();
+    let nested_route = if path.starts_with("/static/") {
+        Some("/static/*")
+    } else if path.starts_with(IMAGES_PREFIX) {
+        Some("/images/*")
+    } else {
+        None
+    };
+    let start = Instant::now();
+
+    let response = next.run(request).await;
+
+    let route = matched_path
+        .as_ref()
+        .map(|matched_path| matched_path.as_str())
+        .or(nested_route)
+        .unwrap_or(UNMATCHED_ROUTE);
+    metrics.record_request(method, route, response.status(), start.elapsed());
+
+    response
+}
+
+fn method_label(method: &Method) -> &'static str {
+    match *method {
+        Method::GET => "GET",
+        Method::HEAD => "HEAD",
+        Method::POST => "POST",
+        Method::PUT => "PUT",
+        Method::DELETE => "DELETE",
+        Method::CONNECT => "CONNECT",
+        Method::OPTIONS => "OPTIONS",
+        Method::TRACE => "TRACE",
+        Method::PATCH => "PATCH",
+        _ => OTHER_METHOD,
+    }
+}
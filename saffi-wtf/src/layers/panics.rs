@@ -0,0 +1,47 @@
+use std::any::Any;
+
+use axum::{
+    http::StatusCode,
+    response::{IntoResponse, Response},
+};
+use tracing::error;
+
+use crate::errors::HandlerError;
+
+/// Turns a panic while handling a request into [`HandlerError::InternalError`],
+/// so that it gets the same error page as any other internal error rather
+/// than dropping the connection, and the panic message gets logged with the
+/// error ID.
+///
+/// This is meant to be used with [`CatchPanicLayer::custom()`].
+///
+/// [`CatchPanicLayer::custom()`]: tower_http::catch_panic::CatchPanicLayer::custom
+pub fn handle_panic(panic: Box<dyn Any + Send + 'static>) -> Response {
+    HandlerError::internal(format!("panicked: {}", panic_message(&*panic))).into_response()
+}
+
+/// Turns a panic while rendering an error page into a bare internal server
+/// error, since there's no error page left to render it with.
+///
+/// This is meant to be used with [`CatchPanicLayer::custom()`], outside of
+/// [`render_error()`].
+///
+/// [`CatchPanicLayer::custom()`]: tower_http::catch_panic::CatchPanicLayer::custom
+/// [`render_error()`]: crate::errors::render_error
+pub fn handle_error_page_panic(panic: Box<dyn Any + Send + 'static>) -> Response {
+    error!(
+        message = panic_message(&*panic),
+        "panicked while rendering error page"
+    );
+    (StatusCode::INTERNAL_SERVER_ERROR, "internal server error\n").into_response()
+}
+
+fn panic_message(panic: &(dyn Any + Send)) -> &str {
+    // Panics with a formatted message carry a `String`, and ones with a
+    // literal message carry a `&'static str`.
+    panic
+        .downcast_ref::<String>()
+        .map(String::as_str)
+        .or_else(|| panic.downcast_ref::<&str>().copied())
+        .unwrap_or("unknown panic payload")
+}
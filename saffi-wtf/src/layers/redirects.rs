@@ -0,0 +1,38 @@
+use axum::{
+    body::Body,
+    extract::State,
+    http::Request,
+    middleware::Next,
+    response::{IntoResponse, Response},
+};
+use tracing::info;
+
+use crate::{errors::HandlerError, layers::permanent_redirect, state::Content};
+
+/// Answers requests for paths in the redirect table with a permanent redirect,
+/// and requests for paths that are gone with [`HandlerError::Gone`], before
+/// they reach the router (and so before they can fall through to
+/// [`not_found()`](crate::handlers::not_found)).
+pub async fn redirect(
+    State(content): State<Content>,
+    request: Request<Body>,
+    next: Next,
+) -> Response {
+    if content.is_gone(request.uri().path()) {
+        info!(route = %request.uri(), "request received for page that's gone");
+        return HandlerError::Gone.into_response();
+    }
+
+    let Some(target) = content.redirect_target(request.uri().path()) else {
+        return next.run(request).await;
+    };
+
+    // Keep the query string, unless the target has its own.
+    let location = match request.uri().query() {
+        Some(query) if target.query().is_none() => format!("{target}?{query}"),
+        _ => target.to_string(),
+    };
+
+    info!(route = %request.uri(), %location, "redirecting request");
+    permanent_redirect(request.method(), location)
+}
@@ -0,0 +1,124 @@
+use std::collections::BTreeSet;
+
+use axum::{
+    body::Body,
+    extract::{FromRef, State},
+    http::{
+        header::{self, HeaderName, InvalidHeaderValue},
+        HeaderMap, HeaderValue, Request,
+    },
+    middleware::Next,
+    response::Response,
+};
+
+use crate::state::State as AppState;
+
+/// Options for the security headers added to every response.
+#[derive(clap::Args, Clone, Debug)]
+pub struct SecurityArgs {
+    /// How long browsers should remember to only use HTTPS, in seconds. The
+    /// `Strict-Transport-Security` header is only sent if this is set.
+    #[arg(long, env = "HSTS_MAX_AGE")]
+    pub hsts_max_age: Option<u64>,
+
+    #[arg(
+        long,
+        env = "REFERRER_POLICY",
+        default_value = "strict-origin-when-cross-origin"
+    )]
+    pub referrer_policy: String,
+
+    #[arg(
+        long,
+        env = "PERMISSIONS_POLICY",
+        default_value = "camera=(), geolocation=(), microphone=()"
+    )]
+    pub permissions_policy: String,
+
+    /// Where browsers should report Content Security Policy violations.
+    #[arg(long, env = "CSP_REPORT_URI")]
+    pub csp_report_uri: Option<String>,
+}
+
+/// The security headers added to every response, built once when the state
+/// is loaded.
+#[derive(Clone, Debug)]
+pub struct SecurityHeaders {
+    headers: HeaderMap,
+}
+
+impl SecurityHeaders {
+    /// Builds the headers from the configured options and the CSP sources for
+    /// every inline `<style>` element the site serves.
+    ///
+    /// Nothing is allowed to run scripts, and styles are only allowed from
+    /// the site's own stylesheet or the given inline styles.
+    pub fn new(
+        args: &SecurityArgs,
+        style_sources: &BTreeSet<String>,
+    ) -> Result<Self, InvalidHeaderValue> {
+        let mut style_src = String::from("'self'");
+        for source in style_sources {
+            style_src.push(' ');
+            style_src.push_str(source);
+        }
+
+        let mut csp = format!(
+            "default-src 'self'; script-src 'none'; style-src {style_src}; img-src 'self' data:; \
+             font-src 'self'; object-src 'none'; base-uri 'self'; form-action 'self'; \
+             frame-ancestors 'none'"
+        );
+        if let Some(report_uri) = &args.csp_report_uri {
+            csp.push_str("; report-uri ");
+            csp.push_str(report_uri);
+        }
+
+        let mut headers = HeaderMap::new();
+        headers.insert(header::CONTENT_SECURITY_POLICY, csp.try_into()?);
+        headers.insert(
+            header::X_CONTENT_TYPE_OPTIONS,
+            HeaderValue::from_static("nosniff"),
+        );
+        headers.insert(
+            header::REFERRER_POLICY,
+            args.referrer_policy.as_str().try_into()?,
+        );
+        headers.insert(
+            HeaderName::from_static("permissions-policy"),
+            args.permissions_policy.as_str().try_into()?,
+        );
+        if let Some(max_age) = args.hsts_max_age {
+            headers.insert(
+                header::STRICT_TRANSPORT_SECURITY,
+                format!("max-age={max_age}; includeSubDomains").try_into()?,
+            );
+        }
+
+        Ok(Self { headers })
+    }
+}
+
+impl FromRef<AppState> for SecurityHeaders {
+    fn from_ref(input: &AppState) -> Self {
+        input.security_headers.clone()
+    }
+}
+
+/// Adds the security headers to every response, unless the handler has
+/// already set its own value for one of them.
+pub async fn add_security_headers(
+    State(security_headers): State<SecurityHeaders>,
+    request: Request<Body>,
+    next: Next,
+) -> Response {
+    let mut response = next.run(request).await;
+
+    let headers = response.headers_mut();
+    for (name, value) in &security_headers.headers {
+        if !headers.contains_key(name) {
+            headers.insert(name, value.clone());
+        }
+    }
+
+    response
+}
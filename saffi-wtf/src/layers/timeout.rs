@@ -0,0 +1,25 @@
+use std::time::Duration;
+
+use axum::{
+    body::Body,
+    extract::State,
+    http::Request,
+    middleware::Next,
+    response::{IntoResponse, Response},
+};
+use tokio::time;
+
+use crate::errors::HandlerError;
+
+/// Gives up on requests that take longer than `timeout` to handle, responding
+/// with [`HandlerError::Timeout`] instead.
+pub async fn time_out(
+    State(timeout): State<Duration>,
+    request: Request<Body>,
+    next: Next,
+) -> Response {
+    match time::timeout(timeout, next.run(request)).await {
+        Ok(response) => response,
+        Err(_) => HandlerError::Timeout(timeout).into_response(),
+    }
+}
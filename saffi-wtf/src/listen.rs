@@ -0,0 +1,202 @@
+#[cfg(unix)]
+use std::os::{
+    fd::{AsRawFd, RawFd},
+    unix::fs::FileTypeExt,
+};
+use std::{
+    fmt, io,
+    net::{self, SocketAddr},
+    str::FromStr,
+};
+
+use camino::Utf8PathBuf;
+use listenfd::ListenFd;
+use tokio::net::{TcpListener, TcpStream};
+#[cfg(unix)]
+use tokio::net::{UnixListener, UnixStream};
+use tracing::info;
+
+/// The prefix that marks a listen address as a path to a Unix socket.
+const UNIX_PREFIX: &str = "unix:";
+
+/// An address to listen on: either a TCP socket address, or a path to a Unix
+/// socket written as `unix:/path/to.sock`.
+#[derive(Clone, Debug)]
+pub enum ListenAddress {
+    Tcp(SocketAddr),
+    #[cfg(unix)]
+    Unix(Utf8PathBuf),
+}
+
+impl FromStr for ListenAddress {
+    type Err = net::AddrParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        #[cfg(unix)]
+        if let Some(path) = s.strip_prefix(UNIX_PREFIX) {
+            return Ok(Self::Unix(path.into()));
+        }
+
+        s.parse().map(Self::Tcp)
+    }
+}
+
+impl fmt::Display for ListenAddress {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Tcp(addr) => addr.fmt(f),
+            #[cfg(unix)]
+            Self::Unix(path) => write!(f, "{UNIX_PREFIX}{path}"),
+        }
+    }
+}
+
+/// A socket listening for connections, either bound by this process or
+/// inherited from whatever started it.
+#[derive(Debug)]
+pub enum Listener {
+    Tcp(TcpListener),
+    #[cfg(unix)]
+    Unix(UnixListener),
+}
+
+impl Listener {
+    pub async fn bind(address: &ListenAddress) -> io::Result<Self> {
+        match address {
+            ListenAddress::Tcp(addr) => TcpListener::bind(addr).await.map(Self::Tcp),
+            #[cfg(unix)]
+            ListenAddress::Unix(path) => {
+                // A socket file left behind by a previous run would stop the
+                // new one being bound. If something is still listening on it
+                // though, it's not stale, and binding should fail.
+                match tokio::fs::symlink_metadata(path).await {
+                    Ok(metadata) if metadata.file_type().is_socket() => {
+                        match UnixStream::connect(path).await {
+                            Ok(_) => {
+                                return Err(io::Error::new(
+                                    io::ErrorKind::AddrInUse,
+                                    format!("something is already listening on {path}"),
+                                ));
+                            }
+                            Err(error) if error.kind() == io::ErrorKind::ConnectionRefused => {
+                                tokio::fs::remove_file(path).await?;
+                                info!(%path, "removed stale Unix socket");
+                            }
+                            Err(error) => return Err(error),
+                        }
+                    }
+                    Ok(_) => {}
+                    Err(error) if error.kind() == io::ErrorKind::NotFound => {}
+                    Err(error) => return Err(error),
+                }
+                UnixListener::bind(path).map(Self::Unix)
+            }
+        }
+    }
+
+    /// Takes the sockets passed to this process with the systemd socket
+    /// activation protocol (the `LISTEN_FDS` and `LISTEN_PID` env vars), in
+    /// the order they were passed.
+    ///
+    /// `listen_fd` has to be taken from the environment before any threads
+    /// are started, since that removes the env vars, and changing the
+    /// environment while another thread might be reading it is a data race.
+    pub fn inherited(mut listen_fd: ListenFd) -> io::Result<Vec<Self>> {
+        (0..listen_fd.len())
+            .filter_map(|index| {
+                // Unix sockets can only be told apart from TCP sockets by
+                // trying to take them as one and then the other.
+                #[cfg(unix)]
+                if let Ok(Some(listener)) = listen_fd.take_unix_listener(index) {
+                    return Some(
+                        listener
+                            .set_nonblocking(true)
+                            .and_then(|()| UnixListener::from_std(listener))
+                            .map(Self::Unix),
+                    );
+                }
+
+                listen_fd
+                    .take_tcp_listener(index)
+                    .transpose()
+                    .map(|listener| {
+                        let listener = listener?;
+                        listener.set_nonblocking(true)?;
+                        TcpListener::from_std(listener).map(Self::Tcp)
+                    })
+            })
+            .collect()
+    }
+
+    /// The port this is listening on, if it's a TCP socket.
+    pub fn port(&self) -> Option<u16> {
+        match self {
+            Self::Tcp(listener) => listener.local_addr().ok().map(|addr| addr.port()),
+            #[cfg(unix)]
+            Self::Unix(_) => None,
+        }
+    }
+
+    pub async fn accept(&self) -> io::Result<Stream> {
+        match self {
+            Self::Tcp(listener) => listener
+                .accept()
+                .await
+                .map(|(stream, _)| Stream::Tcp(stream)),
+            #[cfg(unix)]
+            Self::Unix(listener) => listener
+                .accept()
+                .await
+                .map(|(stream, _)| Stream::Unix(stream)),
+        }
+    }
+}
+
+#[cfg(unix)]
+impl AsRawFd for Listener {
+    fn as_raw_fd(&self) -> RawFd {
+        match self {
+            Self::Tcp(listener) => listener.as_raw_fd(),
+            Self::Unix(listener) => listener.as_raw_fd(),
+        }
+    }
+}
+
+impl fmt::Display for Listener {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Tcp(listener) => match listener.local_addr() {
+                Ok(addr) => addr.fmt(f),
+                Err(_) => f.write_str("unknown TCP address"),
+            },
+            #[cfg(unix)]
+            Self::Unix(listener) => match listener
+                .local_addr()
+                .ok()
+                .as_ref()
+                .and_then(|addr| addr.as_pathname())
+            {
+                Some(path) => write!(f, "{UNIX_PREFIX}{}", path.display()),
+                None => write!(f, "{UNIX_PREFIX}unnamed"),
+            },
+        }
+    }
+}
+
+/// A connection accepted by a [`Listener`].
+pub enum Stream {
+    Tcp(TcpStream),
+    #[cfg(unix)]
+    Unix(UnixStream),
+}
+
+impl Stream {
+    /// The address of the other end of the connection, if it's over TCP.
+    pub fn peer_addr(&self) -> Option<SocketAddr> {
+        match self {
+            Self::Tcp(stream) => stream.peer_addr().ok(),
+            #[cfg(unix)]
+            Self::Unix(_) => None,
+        }
+    }
+}
@@ -1,25 +1,56 @@
-use std::net::SocketAddr;
+use std::time::Duration;
 
-use axum::{body::Body, http::Request, middleware, routing::get, Router};
-use axum_tracing_opentelemetry::middleware::OtelAxumLayer;
+use axum::http::Uri;
 use camino::Utf8PathBuf;
 use clap::Parser;
-use tokio::net::TcpListener;
-use tower::ServiceExt;
-use tower_http::services::ServeDir;
+use listenfd::ListenFd;
 use tracing::{error, info};
+use www_saffi::{Shutdown, TracingArgs};
 
-use crate::state::Config;
+use crate::{
+    health::Health,
+    layers::{
+        access_log::{AccessLog, AccessLogArgs},
+        security::SecurityArgs,
+    },
+    listen::{ListenAddress, Listener},
+    metrics::Metrics,
+    reload::Reloader,
+    site::Site,
+    state::Config,
+    tls::TlsConfig,
+    vhosts::{SiteFile, VirtualHosts},
+};
 
 mod errors;
 mod handlers;
+#[cfg(unix)]
+mod handoff;
+mod health;
+mod layers;
+mod listen;
+mod metrics;
+mod reload;
+mod serve;
+mod site;
 mod state;
 mod templates;
+mod tls;
+mod vhosts;
 
 #[derive(Parser, Clone, Debug)]
 pub struct Args {
     #[arg(long, short, env = "ADDRESS", default_value = "0.0.0.0:4269")]
-    address: SocketAddr,
+    address: ListenAddress,
+
+    #[arg(long, env = "TLS_CERT", requires = "tls_key")]
+    tls_cert: Option<Utf8PathBuf>,
+
+    #[arg(long, env = "TLS_KEY", requires = "tls_cert")]
+    tls_key: Option<Utf8PathBuf>,
+
+    #[arg(long, env = "HTTP_REDIRECT_ADDRESS", requires = "tls_cert")]
+    http_redirect_address: Option<ListenAddress>,
 
     #[arg(long, short, env = "DRAFTS")]
     drafts: bool,
@@ -32,74 +63,300 @@ pub struct Args {
 
     #[arg(long, env = "THEMES_PATH")]
     themes_path: Utf8PathBuf,
+
+    #[arg(long, env = "SYNTAXES_PATH")]
+    syntaxes_path: Utf8PathBuf,
+
+    /// Where resized variants of images are generated. If this isn't set,
+    /// images are served as they are, with only their dimensions added.
+    #[arg(long, env = "IMAGE_CACHE_PATH")]
+    image_cache_path: Option<Utf8PathBuf>,
+
+    #[arg(long, env = "CANONICAL_URL")]
+    canonical_url: Option<Uri>,
+
+    #[command(flatten)]
+    security: SecurityArgs,
+
+    #[command(flatten)]
+    access_log: AccessLogArgs,
+
+    #[command(flatten)]
+    tracing: TracingArgs,
+
+    /// How long to wait for open connections to finish their requests when
+    /// shutting down, in seconds.
+    #[arg(long, env = "DRAIN_TIMEOUT", default_value_t = 30)]
+    drain_timeout: u64,
+
+    /// How long a request can take to handle before it's given up on, in
+    /// seconds.
+    #[arg(long, env = "REQUEST_TIMEOUT", default_value_t = 30)]
+    request_timeout: u64,
+
+    /// Config files for extra sites to serve from this process, by host. The
+    /// site configured by the other arguments is served for any other host.
+    #[arg(long = "site", env = "SITES", value_delimiter = ',')]
+    sites: Vec<Utf8PathBuf>,
+
+    /// The bearer token needed to use the admin endpoints. They aren't
+    /// served unless this is set.
+    #[arg(long, env = "ADMIN_TOKEN", hide_env_values = true)]
+    admin_token: Option<String>,
 }
 
-#[tokio::main]
-async fn main() {
+fn main() {
     dotenv::dotenv().ok();
-    www_saffi::init_tracing();
-
     let args = Args::parse();
 
-    info!(addr = %args.address, "starting server");
+    // Anything that changes the environment has to happen before the runtime
+    // starts any threads, since changing it while another thread might be
+    // reading it is a data race.
+    let listen_fd = ListenFd::from_env();
+    #[cfg(unix)]
+    let ready_notifier = handoff::ReadyNotifier::from_env();
 
-    let listener = match TcpListener::bind(&args.address).await {
-        Ok(listener) => {
-            info!(addr = %args.address, "bound TCP listener");
-            listener
+    let runtime = match tokio::runtime::Builder::new_multi_thread()
+        .enable_all()
+        .build()
+    {
+        Ok(runtime) => runtime,
+        Err(error) => {
+            eprintln!("failed to start runtime: {error}, aborting");
+            return;
         }
+    };
+    runtime.block_on(run(
+        args,
+        listen_fd,
+        #[cfg(unix)]
+        ready_notifier,
+    ));
+}
+
+async fn run(
+    args: Args,
+    listen_fd: ListenFd,
+    #[cfg(unix)] ready_notifier: Option<handoff::ReadyNotifier>,
+) {
+    if let Err(error) = www_saffi::init_tracing(&args.tracing, env!("CARGO_PKG_NAME")) {
+        eprintln!("{error}, aborting");
+        return;
+    }
+
+    // An empty token would let anyone in with an empty `Authorization:
+    // Bearer` header.
+    if args.admin_token.as_deref().is_some_and(str::is_empty) {
+        error!("admin token is empty, aborting");
+        return;
+    }
+
+    let shutdown = Shutdown::new(Duration::from_secs(args.drain_timeout));
+    shutdown.on_shutdown("flush traces", www_saffi::shutdown_tracing);
+
+    info!(addr = %args.address, "starting server");
+
+    // Sockets passed in by systemd socket activation take the place of the
+    // configured addresses, in order: first the main listener, then the HTTP
+    // redirect listener.
+    let mut inherited = match Listener::inherited(listen_fd) {
+        Ok(inherited) => inherited.into_iter(),
         Err(error) => {
-            error!(addr = %args.address, %error, "failed to bind TCP listener, aborting");
+            error!(%error, "failed to take inherited sockets, aborting");
             return;
         }
     };
 
-    let config = Config::from(args);
+    let listener = match inherited.next() {
+        Some(listener) => {
+            info!(%listener, "using inherited listener");
+            listener
+        }
+        None => match Listener::bind(&args.address).await {
+            Ok(listener) => {
+                info!(addr = %args.address, "bound listener");
+                listener
+            }
+            Err(error) => {
+                error!(addr = %args.address, %error, "failed to bind listener, aborting");
+                return;
+            }
+        },
+    };
 
-    let app = Router::new()
-        .route("/", get(handlers::index))
-        .route("/:group", get(handlers::group))
-        .route("/:group/:post", get(handlers::post))
-        .route("/tagged/:tag", get(handlers::tagged))
-        .route("/style.css", get(handlers::stylesheet));
+    let tls = match (args.tls_cert.clone(), args.tls_key.clone()) {
+        (Some(cert_path), Some(key_path)) => match TlsConfig::load(cert_path, key_path).await {
+            Ok(tls) => Some(tls),
+            Err(error) => {
+                error!(%error, "failed to load TLS certificate, aborting");
+                return;
+            }
+        },
+        _ => None,
+    };
+
+    let redirect_listener = match (args.http_redirect_address.as_ref(), inherited.next()) {
+        (Some(_), Some(listener)) => {
+            info!(%listener, "using inherited listener for HTTP redirects");
+            Some(listener)
+        }
+        (Some(addr), None) => match Listener::bind(addr).await {
+            Ok(listener) => {
+                info!(%addr, "bound listener for HTTP redirects");
+                Some(listener)
+            }
+            Err(error) => {
+                error!(%addr, %error, "failed to bind listener for HTTP redirects, aborting");
+                return;
+            }
+        },
+        (None, _) => None,
+    };
+    // Without a TCP port to go by, assume HTTPS is on the standard port, e.g.
+    // behind a proxy.
+    let https_port = listener.port().unwrap_or(443);
 
-    let app = app.nest_service(
-        "/static",
-        ServeDir::new(&config.static_path).map_request(|req: Request<Body>| {
-            info!(route = %req.uri(), under = %"/static", "handling nested request");
-            req
-        }),
-    );
+    let mut site_configs = Vec::with_capacity(args.sites.len());
+    for path in &args.sites {
+        match SiteFile::load(path)
+            .await
+            .and_then(|site| site.into_config(&args))
+        {
+            Ok(site_config) => site_configs.push(site_config),
+            Err(error) => {
+                error!(%error, "failed to load site config, aborting");
+                return;
+            }
+        }
+    }
 
-    #[cfg(debug_assertions)]
-    let app = app.route("/break", get(handlers::internal_error));
+    let metrics = Metrics::default();
 
-    let state = match config.load_state().await {
-        Ok(state) => state,
+    let access_log = match AccessLog::open(&args.access_log, &metrics).await {
+        Ok(access_log) => access_log,
         Err(error) => {
-            error!(%error, "failed to load state, aborting");
+            error!(%error, "failed to open access log, aborting");
             return;
         }
     };
 
-    let app = app
-        .fallback(handlers::not_found)
-        .layer(OtelAxumLayer::default())
-        .layer(middleware::from_fn_with_state(
-            state.clone(),
-            errors::render_error,
-        ))
-        .with_state(state);
-
-    match axum::serve(listener, app.into_make_service())
-        .with_graceful_shutdown(www_saffi::graceful_shutdown())
-        .await
+    let admin_token = args.admin_token.clone();
+
+    let default_site = match Site::load(
+        Config::from(args),
+        metrics.site("default"),
+        access_log.clone(),
+    )
+    .await
     {
-        Ok(_) => {
-            info!("app service exited normally");
-        }
+        Ok(site) => site,
         Err(error) => {
-            error!(%error, "app service exited with error");
+            error!(%error, "failed to load state, aborting");
+            return;
+        }
+    };
+
+    let mut sites = vec![default_site.clone()];
+    let mut virtual_hosts = VirtualHosts::new(default_site);
+    for (hosts, config) in site_configs {
+        let content_path = config.content_path.clone();
+        let site_metrics = metrics.site(hosts.first().map_or("", String::as_str));
+        let site = match Site::load(config, site_metrics, access_log.clone()).await {
+            Ok(site) => site,
+            Err(error) => {
+                error!(%content_path, %error, "failed to load state for site, aborting");
+                return;
+            }
+        };
+
+        if let Err(error) = virtual_hosts.add(&hosts, site.clone()) {
+            error!(%error, "failed to add site, aborting");
+            return;
         }
+        sites.push(site);
+        info!(?hosts, %content_path, "loaded site");
     }
+
+    let health = Health::default();
+    health.loaded();
+    #[cfg(unix)]
+    if let Some(ready_notifier) = ready_notifier {
+        ready_notifier.notify();
+    }
+
+    let reloader = Reloader::new(sites, health.clone(), metrics.clone());
+    tokio::spawn({
+        let reloader = reloader.clone();
+        www_saffi::on_hangup(move || {
+            let reloader = reloader.clone();
+            async move {
+                // Failures are logged, and there's no one else to tell.
+                let _ = reloader.reload().await;
+            }
+        })
+    });
+
+    let mut app = health::routes(health).merge(metrics::routes(metrics));
+    if let Some(admin_token) = admin_token {
+        app = app.merge(reload::routes(reloader, admin_token));
+    }
+    let app = app.fallback_service(virtual_hosts);
+
+    if let Some(tls) = &tls {
+        tokio::spawn(tls.clone().reload_on_change());
+    }
+
+    // The listening sockets are handed over in the same order they're taken
+    // from systemd: first the main listener, then the HTTP redirect listener.
+    #[cfg(unix)]
+    tokio::spawn({
+        use std::os::fd::AsRawFd;
+
+        let listen_fds = [Some(&listener), redirect_listener.as_ref()]
+            .into_iter()
+            .flatten()
+            .map(AsRawFd::as_raw_fd)
+            .collect::<Vec<_>>();
+        let shutdown = shutdown.clone();
+        let token = shutdown.token();
+        let upgrades = www_saffi::on_upgrade_signal(move || {
+            let listen_fds = listen_fds.clone();
+            let shutdown = shutdown.clone();
+            async move {
+                match handoff::upgrade(&listen_fds).await {
+                    Ok(()) => shutdown.request(),
+                    Err(error) => error!(%error, "failed to hand over to new process"),
+                }
+            }
+        });
+        // Once shutdown starts the listeners get closed, so there's nothing
+        // left to hand over.
+        async move {
+            tokio::select! {
+                () = upgrades => {}
+                () = token.requested() => {}
+            }
+        }
+    });
+
+    let redirect_server = async {
+        if let Some(redirect_listener) = redirect_listener {
+            serve::serve(
+                redirect_listener,
+                tls::redirect_to_https_app(https_port),
+                None,
+                shutdown.token(),
+            )
+            .await;
+        }
+    };
+
+    let servers = async {
+        tokio::join!(
+            serve::serve(listener, app, tls, shutdown.token()),
+            redirect_server
+        );
+        info!("app service exited");
+    };
+    shutdown.clone().run(servers).await;
 }
@@ -0,0 +1,383 @@
+use std::{
+    collections::{hash_map::Entry, HashMap},
+    future::Future,
+    sync::{atomic::AtomicU64, Arc, Mutex},
+    time::{Duration, Instant, SystemTime, UNIX_EPOCH},
+};
+
+use axum::{
+    extract::{FromRef, State},
+    http::{header, StatusCode},
+    response::{IntoResponse, Response},
+    routing::get,
+    Router,
+};
+use prometheus_client::{
+    encoding::{text, EncodeLabelSet},
+    metrics::{
+        counter::Counter,
+        family::Family,
+        gauge::Gauge,
+        histogram::{exponential_buckets, Histogram},
+    },
+    registry::Registry,
+};
+use tracing::warn;
+
+use crate::state::State as AppState;
+
+/// How many distinct unknown paths are counted at most, so that scanners
+/// can't grow the table forever. When it's full, every count is halved, and
+/// paths that were only requested once make way for new ones.
+const MAX_UNKNOWN_PATHS: usize = 1000;
+
+/// How many times an unknown path has to be requested before it's reported as
+/// worth adding a redirect for. It's reported again each time the count goes
+/// up by another factor of ten.
+const REPEATED_UNKNOWN_PATH: u64 = 10;
+
+/// The content type of the Prometheus/OpenMetrics text format.
+const OPENMETRICS_CONTENT_TYPE: &str = "application/openmetrics-text; version=1.0.0; charset=utf-8";
+
+type HistogramFamily<S> = Family<S, Histogram, fn() -> Histogram>;
+
+#[derive(Clone, Debug, Hash, PartialEq, Eq, EncodeLabelSet)]
+struct RequestLabels {
+    site: String,
+    method: String,
+    route: String,
+    status: u16,
+}
+
+#[derive(Clone, Debug, Hash, PartialEq, Eq, EncodeLabelSet)]
+struct RenderLabels {
+    site: String,
+    page: &'static str,
+}
+
+#[derive(Clone, Debug, Hash, PartialEq, Eq, EncodeLabelSet)]
+struct SiteLabels {
+    site: String,
+}
+
+#[derive(Clone, Debug, Hash, PartialEq, Eq, EncodeLabelSet)]
+struct ReloadLabels {
+    outcome: &'static str,
+}
+
+#[derive(Clone, Debug, Hash, PartialEq, Eq, EncodeLabelSet)]
+struct ContentLabels {
+    site: String,
+    group: String,
+    kind: &'static str,
+}
+
+/// The metrics for every site served by this process, exposed in the
+/// Prometheus text format on `/metrics`.
+#[derive(Clone, Debug)]
+pub struct Metrics {
+    registry: Arc<Registry>,
+    requests: Family<RequestLabels, Counter>,
+    request_duration: HistogramFamily<RequestLabels>,
+    render_duration: HistogramFamily<RenderLabels>,
+    not_found: Family<SiteLabels, Counter>,
+    content_items: Family<ContentLabels, Gauge>,
+    last_load_timestamp: Family<SiteLabels, Gauge<f64, AtomicU64>>,
+    last_load_duration: Family<SiteLabels, Gauge<f64, AtomicU64>>,
+    reloads: Family<ReloadLabels, Counter>,
+    access_log_dropped: Counter,
+    /// How many times each unknown path has been requested, by site. These
+    /// aren't exported, because every path would be its own series.
+    unknown_paths: Arc<Mutex<HashMap<(String, String), u64>>>,
+}
+
+impl Default for Metrics {
+    fn default() -> Self {
+        let mut registry = Registry::default();
+
+        let requests = Family::default();
+        registry.register(
+            "http_requests",
+            "Requests handled, by route template and status",
+            requests.clone(),
+        );
+
+        let request_duration: HistogramFamily<_> =
+            Family::new_with_constructor(|| Histogram::new(exponential_buckets(0.000_1, 2.0, 16)));
+        registry.register(
+            "http_request_duration_seconds",
+            "Time taken to handle requests, by route template and status",
+            request_duration.clone(),
+        );
+
+        let render_duration: HistogramFamily<_> =
+            Family::new_with_constructor(|| Histogram::new(exponential_buckets(0.000_01, 2.0, 16)));
+        registry.register(
+            "page_render_duration_seconds",
+            "Time taken to render pages from templates, by kind of page",
+            render_duration.clone(),
+        );
+
+        let not_found = Family::default();
+        registry.register(
+            "http_not_found",
+            "Requests for pages that don't exist",
+            not_found.clone(),
+        );
+
+        let content_items = Family::default();
+        registry.register(
+            "content_items",
+            "Posts, pages and tags loaded, by group",
+            content_items.clone(),
+        );
+
+        let last_load_timestamp = Family::default();
+        registry.register(
+            "content_last_load_timestamp_seconds",
+            "When the content was last loaded, as a Unix timestamp",
+            last_load_timestamp.clone(),
+        );
+
+        let last_load_duration = Family::default();
+        registry.register(
+            "content_last_load_duration_seconds",
+            "Time taken to last load the content",
+            last_load_duration.clone(),
+        );
+
+        let reloads = Family::default();
+        registry.register(
+            "content_reloads",
+            "Attempts to reload the content of every site, by outcome",
+            reloads.clone(),
+        );
+
+        let access_log_dropped = Counter::default();
+        registry.register(
+            "access_log_dropped_lines",
+            "Access log lines dropped because the file couldn't keep up",
+            access_log_dropped.clone(),
+        );
+
+        Self {
+            registry: Arc::new(registry),
+            requests,
+            request_duration,
+            render_duration,
+            not_found,
+            content_items,
+            last_load_timestamp,
+            last_load_duration,
+            reloads,
+            access_log_dropped,
+            unknown_paths: Arc::default(),
+        }
+    }
+}
+
+impl Metrics {
+    /// Returns a handle for recording the metrics of one site.
+    pub fn site(&self, site: impl Into<String>) -> SiteMetrics {
+        SiteMetrics {
+            site: site.into(),
+            metrics: self.clone(),
+            content_labels: Arc::default(),
+        }
+    }
+
+    pub fn record_reload(&self, succeeded: bool) {
+        let outcome = if succeeded { "success" } else { "failure" };
+        self.reloads.get_or_create(&ReloadLabels { outcome }).inc();
+    }
+
+    /// Returns the counter of access log lines that were dropped.
+    pub fn access_log_dropped(&self) -> Counter {
+        self.access_log_dropped.clone()
+    }
+}
+
+/// Builds the route for the metrics endpoint.
+///
+/// Like the health endpoints, this is served for every host ahead of the
+/// sites themselves, so scrapes don't show up in the request metrics.
+pub fn routes(metrics: Metrics) -> Router {
+    Router::new()
+        .route("/metrics", get(encode_metrics))
+        .with_state(metrics)
+}
+
+async fn encode_metrics(State(metrics): State<Metrics>) -> Response {
+    let mut body = String::new();
+    if let Err(error) = text::encode(&mut body, &metrics.registry) {
+        warn!(%error, "failed to encode metrics");
+        return StatusCode::INTERNAL_SERVER_ERROR.into_response();
+    }
+
+    ([(header::CONTENT_TYPE, OPENMETRICS_CONTENT_TYPE)], body).into_response()
+}
+
+/// What was loaded for a site, which isn't recorded until the site starts
+/// serving it, in case loading another site fails and it's thrown away.
+#[derive(Clone, Debug)]
+pub struct LoadStats {
+    /// How many of each kind of item were loaded for each group, given as
+    /// the group's route, the kind of item and the count.
+    pub content: Vec<(String, &'static str, usize)>,
+    pub duration: Duration,
+}
+
+/// A handle for recording the metrics of one site, labelled with its name.
+#[derive(Clone, Debug)]
+pub struct SiteMetrics {
+    site: String,
+    metrics: Metrics,
+    /// The labels of the content gauges last recorded for the site, so they
+    /// can be removed when its content is replaced.
+    content_labels: Arc<Mutex<Vec<ContentLabels>>>,
+}
+
+impl SiteMetrics {
+    pub fn record_request(
+        &self,
+        method: &str,
+        route: &str,
+        status: StatusCode,
+        duration: Duration,
+    ) {
+        let labels = RequestLabels {
+            site: self.site.clone(),
+            method: method.to_owned(),
+            route: route.to_owned(),
+            status: status.as_u16(),
+        };
+        self.metrics.requests.get_or_create(&labels).inc();
+        self.metrics
+            .request_duration
+            .get_or_create(&labels)
+            .observe(duration.as_secs_f64());
+    }
+
+    /// Records a request for a path that doesn't exist, and reports it if
+    /// it keeps being requested, along with the closest route that does
+    /// exist, so that a redirect can be added.
+    pub fn record_not_found(&self, path: &str, suggestion: Option<&str>) {
+        self.metrics
+            .not_found
+            .get_or_create(&self.site_labels())
+            .inc();
+
+        let mut unknown_paths = self
+            .metrics
+            .unknown_paths
+            .lock()
+            .expect("lock should not be poisoned");
+        let key = (self.site.clone(), path.to_owned());
+        if unknown_paths.len() >= MAX_UNKNOWN_PATHS && !unknown_paths.contains_key(&key) {
+            unknown_paths.retain(|_, count| {
+                *count /= 2;
+                *count > 0
+            });
+        }
+        let is_full = unknown_paths.len() >= MAX_UNKNOWN_PATHS;
+        let count = match unknown_paths.entry(key) {
+            Entry::Occupied(entry) => entry.into_mut(),
+            Entry::Vacant(_) if is_full => return,
+            Entry::Vacant(entry) => entry.insert(0),
+        };
+        *count += 1;
+
+        let mut threshold = REPEATED_UNKNOWN_PATH;
+        while threshold < *count {
+            threshold = threshold.saturating_mul(10);
+        }
+        if *count == threshold {
+            warn!(
+                site = %self.site,
+                path,
+                count = *count,
+                suggestion,
+                "unknown path keeps being requested, it might need a redirect"
+            );
+        }
+    }
+
+    /// Renders a page, recording how long it took.
+    pub async fn time_render<F: Future>(&self, page: &'static str, render: F) -> F::Output {
+        let start = Instant::now();
+        let rendered = render.await;
+
+        let labels = RenderLabels {
+            site: self.site.clone(),
+            page,
+        };
+        self.metrics
+            .render_duration
+            .get_or_create(&labels)
+            .observe(start.elapsed().as_secs_f64());
+
+        rendered
+    }
+
+    /// Records what was loaded for the site, once it's being served.
+    ///
+    /// This replaces everything recorded for the site's content before, so
+    /// that groups which have been removed don't linger.
+    pub fn record_load(&self, stats: &LoadStats) {
+        self.record_content(&stats.content);
+
+        // Paths that didn't exist before might now, so start counting again.
+        self.metrics
+            .unknown_paths
+            .lock()
+            .expect("lock should not be poisoned")
+            .retain(|(site, _), _| *site != self.site);
+
+        let timestamp = SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .unwrap_or_default();
+        self.metrics
+            .last_load_timestamp
+            .get_or_create(&self.site_labels())
+            .set(timestamp.as_secs_f64());
+        self.metrics
+            .last_load_duration
+            .get_or_create(&self.site_labels())
+            .set(stats.duration.as_secs_f64());
+    }
+
+    fn record_content(&self, counts: &[(String, &'static str, usize)]) {
+        let mut content_labels = self
+            .content_labels
+            .lock()
+            .expect("lock should not be poisoned");
+        for labels in content_labels.drain(..) {
+            self.metrics.content_items.remove(&labels);
+        }
+
+        for (group, kind, count) in counts {
+            let labels = ContentLabels {
+                site: self.site.clone(),
+                group: group.clone(),
+                kind,
+            };
+            self.metrics
+                .content_items
+                .get_or_create(&labels)
+                .set((*count).try_into().unwrap_or(i64::MAX));
+            content_labels.push(labels);
+        }
+    }
+
+    fn site_labels(&self) -> SiteLabels {
+        SiteLabels {
+            site: self.site.clone(),
+        }
+    }
+}
+
+impl FromRef<AppState> for SiteMetrics {
+    fn from_ref(input: &AppState) -> Self {
+        input.metrics.clone()
+    }
+}
@@ -0,0 +1,127 @@
+use std::sync::Arc;
+
+use axum::{
+    extract::State,
+    http::{header, HeaderMap, StatusCode},
+    response::{IntoResponse, Response},
+    routing::post,
+    Router,
+};
+use camino::Utf8PathBuf;
+use sha2::{Digest, Sha256};
+use thiserror::Error;
+use tokio::sync::Mutex;
+use tracing::{error, info};
+
+use crate::{health::Health, metrics::Metrics, site::Site, state::LoadStateError};
+
+/// Reloads the content of every site, on request.
+#[derive(Clone)]
+pub struct Reloader {
+    sites: Arc<Vec<Site>>,
+    health: Health,
+    metrics: Metrics,
+    /// Held while reloading, so that only one reload happens at a time.
+    reloading: Arc<Mutex<()>>,
+}
+
+impl Reloader {
+    pub fn new(sites: Vec<Site>, health: Health, metrics: Metrics) -> Self {
+        Self {
+            sites: Arc::new(sites),
+            health,
+            metrics,
+            reloading: Arc::default(),
+        }
+    }
+
+    /// Loads the content of every site again, and swaps it in.
+    ///
+    /// Nothing is swapped in unless every site loads successfully, so if
+    /// anything goes wrong, every site carries on serving its old content.
+    pub async fn reload(&self) -> Result<(), ReloadError> {
+        let _reloading = self.reloading.lock().await;
+        info!(sites = self.sites.len(), "reloading content");
+
+        let result = self.rebuild_all().await;
+        match &result {
+            Ok(()) => {
+                self.health.loaded();
+                info!("reloaded content");
+            }
+            Err(error) => {
+                self.health.failed(error.to_string());
+                error!(%error, "failed to reload content, keeping old content");
+            }
+        }
+        self.metrics.record_reload(result.is_ok());
+
+        result
+    }
+
+    async fn rebuild_all(&self) -> Result<(), ReloadError> {
+        let mut apps = Vec::with_capacity(self.sites.len());
+        for site in self.sites.iter() {
+            let app = site
+                .rebuild()
+                .await
+                .map_err(|err| ReloadError::LoadSite(site.config().content_path.clone(), err))?;
+            apps.push(app);
+        }
+
+        for (site, app) in self.sites.iter().zip(apps) {
+            site.replace(app);
+        }
+
+        Ok(())
+    }
+}
+
+#[derive(Error, Debug)]
+pub enum ReloadError {
+    #[error("failed to load site from {0}: {1}")]
+    LoadSite(Utf8PathBuf, #[source] LoadStateError),
+}
+
+/// Builds the route for the admin endpoint that triggers a reload, which
+/// needs the token given here as a bearer token.
+///
+/// Like the health endpoints, this is served for every host ahead of the
+/// sites themselves.
+pub fn routes(reloader: Reloader, admin_token: String) -> Router {
+    Router::new()
+        .route("/_admin/reload", post(reload))
+        .with_state(AdminState {
+            reloader,
+            token_hash: Sha256::digest(admin_token).into(),
+        })
+}
+
+#[derive(Clone)]
+struct AdminState {
+    reloader: Reloader,
+    token_hash: [u8; 32],
+}
+
+async fn reload(State(admin): State<AdminState>, headers: HeaderMap) -> Response {
+    // Comparing hashes means the time taken doesn't depend on how much of
+    // the token was right.
+    let authorised = headers
+        .get(header::AUTHORIZATION)
+        .and_then(|value| value.to_str().ok())
+        .and_then(|value| value.strip_prefix("Bearer "))
+        .is_some_and(|token| <[u8; 32]>::from(Sha256::digest(token)) == admin.token_hash);
+    if !authorised {
+        return (
+            StatusCode::UNAUTHORIZED,
+            [(header::WWW_AUTHENTICATE, "Bearer")],
+            "unauthorised\n",
+        )
+            .into_response();
+    }
+
+    match admin.reloader.reload().await {
+        Ok(()) => (StatusCode::OK, "reloaded\n").into_response(),
+        Err(error) => (StatusCode::INTERNAL_SERVER_ERROR, format!("{error}\n")).into_response(),
+    }
+}
@@ -0,0 +1,304 @@
+use std::{net::SocketAddr, time::Duration};
+
+use axum::{extract::ConnectInfo, http::Request, Router};
+use hyper::body::Incoming;
+use hyper_util::{
+    rt::{TokioExecutor, TokioIo},
+    server::conn::auto::Builder,
+    service::TowerToHyperService,
+};
+use tokio::{
+    io::{AsyncRead, AsyncWrite},
+    sync::watch,
+    time,
+};
+use tower::ServiceExt;
+use tracing::{debug, info, warn};
+use www_saffi::ShutdownToken;
+
+use crate::{
+    listen::{Listener, Stream},
+    tls::TlsConfig,
+};
+
+/// How long to wait before accepting again after the first failure to
+/// accept a connection. This doubles with each failure in a row, up to
+/// [`MAX_ACCEPT_BACKOFF`].
+const MIN_ACCEPT_BACKOFF: Duration = Duration::from_millis(5);
+
+/// The longest to wait before accepting again after failing to accept a
+/// connection.
+const MAX_ACCEPT_BACKOFF: Duration = Duration::from_secs(1);
+
+/// How long a client has to finish the TLS handshake before the connection is
+/// dropped.
+const TLS_HANDSHAKE_TIMEOUT: Duration = Duration::from_secs(10);
+
+/// Serves an app on a listener, over HTTP/1.1 or HTTP/2, optionally with TLS.
+///
+/// Once shutdown is requested, no more connections are accepted, and this waits
+/// for the open connections to finish their in-flight requests before
+/// returning.
+pub async fn serve(
+    listener: Listener,
+    app: Router,
+    tls: Option<TlsConfig>,
+    shutdown: ShutdownToken,
+) {
+    // Every connection holds a receiver, so that it can be told to close, and
+    // so that the sender can tell when they've all finished.
+    let (close_tx, close_rx) = watch::channel(());
+    let mut accept_backoff = MIN_ACCEPT_BACKOFF;
+
+    loop {
+        let stream = tokio::select! {
+            accepted = listener.accept() => match accepted {
+                Ok(accepted) => {
+                    accept_backoff = MIN_ACCEPT_BACKOFF;
+                    accepted
+                }
+                Err(error) => {
+                    // Errors like running out of file descriptors won't go
+                    // away by trying again straight away, so back off rather
+                    // than spin.
+                    warn!(
+                        %error,
+                        backoff_ms = accept_backoff.as_millis(),
+                        "failed to accept connection"
+                    );
+                    tokio::select! {
+                        () = time::sleep(accept_backoff) => {}
+                        () = shutdown.requested() => break,
+                    }
+                    accept_backoff = (accept_backoff * 2).min(MAX_ACCEPT_BACKOFF);
+                    continue;
+                }
+            },
+            () = shutdown.requested() => break,
+        };
+
+        let app = app.clone();
+        let tls = tls.clone();
+        let close_rx = close_rx.clone();
+        let peer_addr = stream.peer_addr();
+        tokio::spawn(async move {
+            match stream {
+                Stream::Tcp(stream) => {
+                    accept_connection(stream, peer_addr, app, tls, close_rx).await
+                }
+                #[cfg(unix)]
+                Stream::Unix(stream) => {
+                    accept_connection(stream, peer_addr, app, tls, close_rx).await
+                }
+            }
+        });
+    }
+
+    drop(close_rx);
+    drop(listener);
+
+    info!(
+        connections = close_tx.receiver_count(),
+        "waiting for open connections to close"
+    );
+    close_tx.send_replace(());
+    close_tx.closed().await;
+}
+
+async fn accept_connection<I>(
+    io: I,
+    peer_addr: Option<SocketAddr>,
+    app: Router,
+    tls: Option<TlsConfig>,
+    mut close_rx: watch::Receiver<()>,
+) where
+    I: AsyncRead + AsyncWrite + Send + Unpin + 'static,
+{
+    let Some(tls) = tls else {
+        return serve_connection(io, peer_addr, app, close_rx).await;
+    };
+
+    // A client that never finishes the handshake shouldn't hold the
+    // connection open forever, or hold up shutdown.
+    let handshake = time::timeout(TLS_HANDSHAKE_TIMEOUT, tls.acceptor().accept(io));
+    let io = tokio::select! {
+        result = handshake => match result {
+            Ok(Ok(io)) => io,
+            Ok(Err(error)) => {
+                debug!(%error, "TLS handshake failed");
+                return;
+            }
+            Err(_) => {
+                debug!("TLS handshake timed out");
+                return;
+            }
+        },
+        _ = close_rx.changed() => {
+            debug!("dropping connection mid TLS handshake to shut down");
+            return;
+        }
+    };
+
+    serve_connection(io, peer_addr, app, close_rx).await;
+}
+
+async fn serve_connection<I>(
+    io: I,
+    peer_addr: Option<SocketAddr>,
+    app: Router,
+    mut close_rx: watch::Receiver<()>,
+) where
+    I: AsyncRead + AsyncWrite + Send + Unpin + 'static,
+{
+    // Make the peer's address available to the app in the same way as
+    // `into_make_service_with_connect_info()` would.
+    let app = app.map_request(move |mut request: Request<Incoming>| {
+        if let Some(peer_addr) = peer_addr {
+            request.extensions_mut().insert(ConnectInfo(peer_addr));
+        }
+        request
+    });
+
+    let builder = Builder::new(TokioExecutor::new());
+    let connection =
+        builder.serve_connection_with_upgrades(TokioIo::new(io), TowerToHyperService::new(app));
+    tokio::pin!(connection);
+
+    let result = tokio::select! {
+        result = connection.as_mut() => result,
+        _ = close_rx.changed() => {
+            connection.as_mut().graceful_shutdown();
+            connection.await
+        }
+    };
+
+    if let Err(error) = result {
+        debug!(%error, "connection closed with error");
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::{fs, sync::Arc, time::Duration};
+
+    use axum::{routing::get, Router};
+    use camino::Utf8PathBuf;
+    use tokio::{
+        io::{AsyncReadExt, AsyncWriteExt},
+        net::TcpStream,
+        task::JoinHandle,
+        time,
+    };
+    use tokio_rustls::{
+        rustls::{
+            pki_types::{CertificateDer, ServerName},
+            ClientConfig, RootCertStore,
+        },
+        TlsConnector,
+    };
+    use uuid::Uuid;
+    use www_saffi::Shutdown;
+
+    use super::serve;
+    use crate::{
+        listen::{ListenAddress, Listener},
+        tls::TlsConfig,
+    };
+
+    /// A server on a random local port, serving over TLS with a self-signed
+    /// certificate for `localhost`.
+    struct TestServer {
+        port: u16,
+        cert_der: Vec<u8>,
+        shutdown: Shutdown,
+        serving: JoinHandle<()>,
+        dir: Utf8PathBuf,
+    }
+
+    impl TestServer {
+        async fn start() -> Self {
+            let cert = rcgen::generate_simple_self_signed(vec!["localhost".to_owned()]).unwrap();
+            let dir = Utf8PathBuf::try_from(std::env::temp_dir())
+                .unwrap()
+                .join(format!("www-saffi-wtf-tls-{}", Uuid::new_v4().simple()));
+            fs::create_dir_all(&dir).unwrap();
+            fs::write(dir.join("cert.pem"), cert.serialize_pem().unwrap()).unwrap();
+            fs::write(dir.join("key.pem"), cert.serialize_private_key_pem()).unwrap();
+
+            let tls = TlsConfig::load(dir.join("cert.pem"), dir.join("key.pem"))
+                .await
+                .unwrap();
+            let listener = Listener::bind(&ListenAddress::Tcp("127.0.0.1:0".parse().unwrap()))
+                .await
+                .unwrap();
+            let port = listener.port().unwrap();
+
+            let app = Router::new().route("/", get(|| async { "hello over TLS" }));
+            let shutdown = Shutdown::new(Duration::from_secs(1));
+            let serving = tokio::spawn(serve(listener, app, Some(tls), shutdown.token()));
+
+            Self {
+                port,
+                cert_der: cert.serialize_der().unwrap(),
+                shutdown,
+                serving,
+                dir,
+            }
+        }
+
+        async fn stop(self) {
+            self.shutdown.request();
+            time::timeout(Duration::from_secs(5), self.serving)
+                .await
+                .expect("server should stop promptly")
+                .unwrap();
+            fs::remove_dir_all(self.dir).unwrap();
+        }
+    }
+
+    #[tokio::test]
+    async fn serves_requests_over_tls() {
+        let server = TestServer::start().await;
+
+        let mut roots = RootCertStore::empty();
+        roots
+            .add(CertificateDer::from(server.cert_der.clone()))
+            .unwrap();
+        let client_config = ClientConfig::builder()
+            .with_root_certificates(roots)
+            .with_no_client_auth();
+        let connector = TlsConnector::from(Arc::new(client_config));
+
+        let tcp = TcpStream::connect(("127.0.0.1", server.port))
+            .await
+            .unwrap();
+        let mut stream = connector
+            .connect(ServerName::try_from("localhost").unwrap(), tcp)
+            .await
+            .unwrap();
+        stream
+            .write_all(b"GET / HTTP/1.1\r\nHost: localhost\r\nConnection: close\r\n\r\n")
+            .await
+            .unwrap();
+        let mut response = String::new();
+        stream.read_to_string(&mut response).await.unwrap();
+
+        assert!(response.starts_with("HTTP/1.1 200 OK\r\n"), "{response}");
+        assert!(response.ends_with("hello over TLS"), "{response}");
+
+        server.stop().await;
+    }
+
+    #[tokio::test]
+    async fn stalled_handshakes_dont_hold_up_shutdown() {
+        let server = TestServer::start().await;
+
+        // Connect, but never start the handshake.
+        let _tcp = TcpStream::connect(("127.0.0.1", server.port))
+            .await
+            .unwrap();
+        time::sleep(Duration::from_millis(50)).await;
+
+        server.stop().await;
+    }
+}
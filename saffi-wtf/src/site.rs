@@ -0,0 +1,168 @@
+use std::{
+    convert::Infallible,
+    sync::{Arc, RwLock},
+    task::{Context, Poll},
+};
+
+use axum::{
+    body::Body,
+    http::Request,
+    middleware,
+    response::Response,
+    routing::{future::RouteFuture, get},
+    Router,
+};
+use axum_tracing_opentelemetry::middleware::OtelAxumLayer;
+use tower::Service;
+use tower_http::{catch_panic::CatchPanicLayer, services::ServeDir};
+
+use crate::{
+    errors, handlers,
+    layers::{self, access_log::AccessLog},
+    metrics::{LoadStats, SiteMetrics},
+    state::{images::IMAGES_PREFIX, Config, LoadStateError},
+};
+
+/// A site served by this process, whose app can be rebuilt from its config
+/// and swapped in while it's serving.
+#[derive(Clone)]
+pub struct Site {
+    config: Config,
+    metrics: SiteMetrics,
+    access_log: AccessLog,
+    app: Arc<RwLock<Router>>,
+}
+
+impl Site {
+    pub async fn load(
+        config: Config,
+        metrics: SiteMetrics,
+        access_log: AccessLog,
+    ) -> Result<Self, LoadStateError> {
+        let app = build_app(config.clone(), metrics.clone(), access_log.clone()).await?;
+        metrics.record_load(&app.stats);
+
+        Ok(Self {
+            config,
+            metrics,
+            access_log,
+            app: Arc::new(RwLock::new(app.router)),
+        })
+    }
+
+    pub fn config(&self) -> &Config {
+        &self.config
+    }
+
+    /// Builds a new app for this site, loading its content again. The old
+    /// app carries on serving until the new one is swapped in with
+    /// [`replace()`](Self::replace).
+    pub async fn rebuild(&self) -> Result<App, LoadStateError> {
+        build_app(
+            self.config.clone(),
+            self.metrics.clone(),
+            self.access_log.clone(),
+        )
+        .await
+    }
+
+    /// Starts serving every new request with `app`. Requests already being
+    /// handled finish with the old app.
+    pub fn replace(&self, app: App) {
+        *self.app.write().expect("lock should not be poisoned") = app.router;
+        self.metrics.record_load(&app.stats);
+    }
+}
+
+/// An app built for a site, along with what was loaded for it.
+pub struct App {
+    router: Router,
+    stats: LoadStats,
+}
+
+impl Service<Request<Body>> for Site {
+    type Response = Response;
+    type Error = Infallible;
+    type Future = RouteFuture<Infallible>;
+
+    fn poll_ready(&mut self, _cx: &mut Context<'_>) -> Poll<Result<(), Self::Error>> {
+        Poll::Ready(Ok(()))
+    }
+
+    fn call(&mut self, request: Request<Body>) -> Self::Future {
+        let mut app = self
+            .app
+            .read()
+            .expect("lock should not be poisoned")
+            .clone();
+        app.call(request)
+    }
+}
+
+/// Builds the app for a single site, loading its state from the config.
+async fn build_app(
+    config: Config,
+    metrics: SiteMetrics,
+    access_log: AccessLog,
+) -> Result<App, LoadStateError> {
+    let app = Router::new()
+        .route("/", get(handlers::index))
+        .route("/:group", get(handlers::group))
+        .route("/:group/:post", get(handlers::post))
+        .route("/tagged/:tag", get(handlers::tagged))
+        .route("/style.css", get(handlers::stylesheet));
+
+    let app = app.nest_service("/static", ServeDir::new(&config.static_path));
+
+    let app = match &config.image_cache_path {
+        Some(image_cache_path) => app.nest_service(
+            IMAGES_PREFIX.trim_end_matches('/'),
+            ServeDir::new(image_cache_path),
+        ),
+        None => app,
+    };
+
+    #[cfg(debug_assertions)]
+    let app = app.route("/break", get(handlers::internal_error));
+
+    let request_timeout = config.request_timeout;
+    let (state, stats) = config.load_state(metrics).await?;
+
+    let router = app
+        .fallback(handlers::not_found)
+        .layer(middleware::from_fn_with_state(
+            state.clone(),
+            layers::redirects::redirect,
+        ))
+        .layer(middleware::from_fn_with_state(
+            state.clone(),
+            layers::canonical::canonicalise,
+        ))
+        .layer(OtelAxumLayer::default())
+        .layer(middleware::from_fn_with_state(
+            request_timeout,
+            layers::timeout::time_out,
+        ))
+        .layer(CatchPanicLayer::custom(layers::panics::handle_panic))
+        .layer(middleware::from_fn_with_state(
+            state.clone(),
+            errors::render_error,
+        ))
+        .layer(CatchPanicLayer::custom(
+            layers::panics::handle_error_page_panic,
+        ))
+        .layer(middleware::from_fn_with_state(
+            state.clone(),
+            layers::metrics::record_request,
+        ))
+        .layer(middleware::from_fn_with_state(
+            state.clone(),
+            layers::security::add_security_headers,
+        ))
+        .layer(middleware::from_fn_with_state(
+            access_log,
+            layers::access_log::log_request,
+        ))
+        .with_state(state);
+    Ok(App { router, stats })
+}
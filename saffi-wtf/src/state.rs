@@ -1,37 +1,65 @@
 use std::{
-    collections::{HashMap, HashSet},
+    collections::{BTreeSet, HashMap, HashSet},
     io,
-    path::PathBuf,
-    sync::Arc,
+    path::{Path, PathBuf},
+    sync::{Arc, Mutex},
+    time::{Duration, Instant},
 };
 
-use axum::extract::FromRef;
+use axum::{
+    extract::FromRef,
+    http::{header::InvalidHeaderValue, Uri},
+};
 use camino::Utf8PathBuf;
 use chrono::naive::NaiveDate;
-use comrak::{
-    markdown_to_html_with_plugins, plugins::syntect::SyntectAdapter, ComrakOptions, ComrakPlugins,
-};
+use comrak::{format_html_with_plugins, parse_document, Arena, ComrakOptions, ComrakPlugins};
 use maud::{html, Markup, PreEscaped};
 use serde::Deserialize;
 use syntect::{
     highlighting::ThemeSet as SyntectThemeSet,
     html::{css_for_theme_with_class_style, ClassStyle},
+    parsing::SyntaxSet,
     Error as SyntectError, LoadingError as SyntectLoadingError,
 };
 use thiserror::Error;
-use tokio::fs::{self, DirEntry};
-use tracing::info;
+use tokio::{
+    fs::{self, DirEntry},
+    task,
+};
+use tracing::{field, info, info_span, instrument, warn, Instrument, Span};
 
 use crate::{
+    layers::security::{SecurityArgs, SecurityHeaders},
+    metrics::{LoadStats, SiteMetrics},
     state::{
+        canonical::{Canonical, InvalidCanonicalUrlError},
+        diagrams::RenderedDiagrams,
+        highlight::CodeBlockAdapter,
+        images::{ImageProcessor, ProcessImageError},
+        integrity::{csp_hash_source, inline_styles, sri_hash},
+        math::{ExtractedMath, RenderMathError},
         names::{GroupName, PageName, ParseGroupNameError, ParsePageNameError, TagName},
+        redirects::{GoneFile, RedirectError, Redirects, GONE_FILE_NAME, REDIRECTS_FILE_NAME},
         render::{GroupRef, PostRef, TagRef},
     },
     Args,
 };
 
+pub mod canonical;
+pub mod diagrams;
+pub mod highlight;
+pub mod images;
+pub mod integrity;
+pub mod math;
 pub mod names;
+pub mod redirects;
 pub mod render;
+pub mod suggestions;
+pub mod summary;
+pub mod text;
+
+/// The site's stylesheet, compiled from SCSS by the build script.
+pub const STYLESHEET: &str = include_str!(concat!(env!("OUT_DIR"), "/style.css"));
 
 #[derive(Clone, Debug)]
 pub struct Config {
@@ -39,6 +67,11 @@ pub struct Config {
     pub content_path: Utf8PathBuf,
     pub static_path: Utf8PathBuf,
     pub themes_path: Utf8PathBuf,
+    pub syntaxes_path: Utf8PathBuf,
+    pub image_cache_path: Option<Utf8PathBuf>,
+    pub canonical_url: Option<Uri>,
+    pub security: SecurityArgs,
+    pub request_timeout: Duration,
 }
 
 impl From<Args> for Config {
@@ -48,6 +81,11 @@ impl From<Args> for Config {
             content_path,
             static_path,
             themes_path,
+            syntaxes_path,
+            image_cache_path,
+            canonical_url,
+            security,
+            request_timeout,
             ..
         } = args;
         Self {
@@ -55,26 +93,100 @@ impl From<Args> for Config {
             content_path,
             static_path,
             themes_path,
+            syntaxes_path,
+            image_cache_path,
+            canonical_url,
+            security,
+            request_timeout: Duration::from_secs(request_timeout),
         }
     }
 }
 
 impl Config {
-    pub async fn load_state(self) -> Result<State, LoadStateError> {
+    #[instrument(skip_all, fields(content_path = %self.content_path))]
+    pub async fn load_state(
+        self,
+        metrics: SiteMetrics,
+    ) -> Result<(State, LoadStats), LoadStateError> {
         use LoadStateError::*;
 
-        let theme_set = SyntectThemeSet::load_from_folder(self.themes_path)?;
-        let theme = Theme::try_load(theme_set, "OneHalfLight", "OneHalfDark")?;
-
-        let syntect_adapter = SyntectAdapter::new(None);
+        let load_start = Instant::now();
+
+        let theme = info_span!("load_theme", themes_path = %self.themes_path).in_scope(|| {
+            let theme_set = SyntectThemeSet::load_from_folder(&self.themes_path)?;
+            Ok::<_, LoadStateError>(Theme::try_load(theme_set, "OneHalfLight", "OneHalfDark")?)
+        })?;
+
+        let syntax_set = info_span!("load_syntaxes", syntaxes_path = %self.syntaxes_path)
+            .in_scope(|| {
+                let mut builder = SyntaxSet::load_defaults_newlines().into_builder();
+                builder
+                    .add_from_folder(&self.syntaxes_path, true)
+                    .map_err(LoadSyntaxSet)?;
+                Ok::<_, LoadStateError>(builder.build())
+            })?;
+        info!(
+            languages = %syntax_set
+                .syntaxes()
+                .iter()
+                .map(|syntax| syntax.name.as_str())
+                .collect::<Vec<_>>()
+                .join(", "),
+            "loaded syntax set"
+        );
+
+        let code_block_adapter = CodeBlockAdapter::new(syntax_set);
         let plugins = {
             let mut plugins = ComrakPlugins::default();
-            plugins.render.codefence_syntax_highlighter = Some(&syntect_adapter);
+            plugins.render.codefence_syntax_highlighter = Some(&code_block_adapter);
             plugins
         };
-        let options = ComrakOptions::default();
+        let options = markdown_options();
+
+        // Every inline `<style>` element in the content (like the ones in
+        // diagrams) needs to be allowed by the Content Security Policy.
+        let style_sources = Mutex::new(BTreeSet::from([theme.style_source.clone()]));
+
+        let markdown_to_html = |md: &str, path: &Path| {
+            let span = info_span!(
+                "render_markdown",
+                path = %path.display(),
+                bytes = md.len(),
+                duration_ms = field::Empty,
+            )
+            .entered();
+            let render_start = Instant::now();
+
+            let (md, math) =
+                ExtractedMath::extract(md).map_err(|err| RenderMath(path.to_owned(), err))?;
+
+            let arena = Arena::new();
+            let root = parse_document(&arena, &md, &options);
+
+            let diagrams = RenderedDiagrams::render(&arena, root, path);
+
+            let mut html = Vec::new();
+            format_html_with_plugins(root, &options, &mut html, &plugins)
+                .map_err(|err| RenderMarkdown(path.to_owned(), err))?;
+            let html = String::from_utf8(html).expect("comrak should only output valid UTF-8");
+            let html = diagrams.restore(&math.restore(&html));
+
+            style_sources
+                .lock()
+                .expect("lock should not be poisoned")
+                .extend(inline_styles(&html).map(csp_hash_source));
+
+            span.record("duration_ms", elapsed_ms(render_start));
+            Ok::<_, LoadStateError>(html)
+        };
 
-        let markdown_to_html = |md: &str| markdown_to_html_with_plugins(md, &options, &plugins);
+        // Posts' summaries are rendered separately from the rest of them, so
+        // this is only done once everything in a file has been rendered.
+        let report_unknown_languages = |path: &Path| {
+            for lang in code_block_adapter.take_unknown_languages() {
+                warn!(?path, %lang, "code block uses a language with no known syntax");
+            }
+        };
 
         let mut groups = GroupsMap::new();
         let mut tags = TagsMap::new();
@@ -82,12 +194,14 @@ impl Config {
         let mut posts = PostsMap::new();
         let mut groups_to_load = Vec::new();
 
+        let options = &options;
         let load_page = |entry: DirEntry,
                          group_context: GroupName,
                          mut groups: GroupsMap,
                          mut tags: TagsMap,
                          mut pages: PagesMap,
                          mut posts: PostsMap| async move {
+            let page_start = Instant::now();
             let path = entry.path();
             let file_name = path
                 .file_stem()
@@ -110,6 +224,14 @@ impl Config {
                 let name = PageName::new_index();
                 groups.entry(group_context).or_default().index = Some(name.clone());
                 name
+            } else if let Some(kind) = ErrorPageKind::from_file_stem(&file_name) {
+                let name = PageName::new_error_page();
+                groups
+                    .entry(group_context)
+                    .or_default()
+                    .error_pages
+                    .insert(kind, name.clone());
+                name
             } else {
                 let name: PageName = file_name.clone().try_into()?;
                 groups
@@ -121,6 +243,7 @@ impl Config {
             };
 
             let raw_content = fs::read_to_string(&path).await.map_err(ReadPageContent)?;
+            Span::current().record("bytes", raw_content.len());
 
             if let Ok((date, _)) = NaiveDate::parse_and_remainder(&file_name, "%Y-%m-%d") {
                 let (raw_frontmatter, raw_markdown) = raw_content
@@ -130,7 +253,8 @@ impl Config {
                     .split_once("---")
                     .ok_or_else(|| MalformedFrontmatter(entry.path()))?;
 
-                let frontmatter = toml::from_str::<PostFrontmatter>(raw_frontmatter)?;
+                let frontmatter = info_span!("parse_frontmatter")
+                    .in_scope(|| toml::from_str::<PostFrontmatter>(raw_frontmatter))?;
 
                 for tag in frontmatter.tags.iter().cloned() {
                     tags.entry(tag)
@@ -142,87 +266,306 @@ impl Config {
                 if frontmatter.draft && !self.drafts {
                     info!(?path, "skipping draft");
                 } else {
-                    let html_content = markdown_to_html(raw_markdown);
+                    let (summary_markdown, full_markdown) =
+                        match summary::split_at_marker(raw_markdown, options) {
+                            Some((before, full)) => (Some(before), full),
+                            None => (frontmatter.summary.as_deref(), raw_markdown.to_owned()),
+                        };
+
+                    let html_content = markdown_to_html(&full_markdown, &path)?;
+                    let title = summary::title(&html_content).map(str::to_owned);
+                    let summary_html = match summary_markdown {
+                        Some(summary_markdown) => {
+                            summary::strip_title(&markdown_to_html(summary_markdown, &path)?)
+                        }
+                        None => summary::first_paragraph(&html_content)
+                            .unwrap_or_default()
+                            .to_owned(),
+                    };
+                    let word_count = summary::word_count(&full_markdown);
+                    let markdown_start = offset_in(&raw_content, raw_markdown);
 
                     posts.insert(
                         page_name,
                         Post {
                             date,
                             frontmatter,
+                            title,
                             html_content,
+                            summary_html,
+                            word_count,
+                            raw_content,
+                            markdown_start,
                         },
                     );
 
                     info!(?path, "loaded post");
                 }
             } else {
-                let raw_markdown = if let Some(stripped_once) = raw_content.strip_prefix("---") {
-                    // For now, we're ignoring any frontmatter. Later, when static pages need
-                    // frontmatter, we'll read this.
-                    let (_, raw_markdown) = stripped_once
-                        .trim()
-                        .split_once("---")
-                        .ok_or_else(|| MalformedFrontmatter(entry.path()))?;
-                    raw_markdown
-                } else {
-                    raw_content.as_str()
-                };
-
-                let html_content = markdown_to_html(raw_markdown);
-
-                pages.insert(page_name, Page { html_content });
+                let (frontmatter, raw_markdown) =
+                    if let Some(stripped_once) = raw_content.strip_prefix("---") {
+                        let (raw_frontmatter, raw_markdown) = stripped_once
+                            .trim()
+                            .split_once("---")
+                            .ok_or_else(|| MalformedFrontmatter(entry.path()))?;
+                        let frontmatter = info_span!("parse_frontmatter")
+                            .in_scope(|| toml::from_str::<PageFrontmatter>(raw_frontmatter))?;
+                        (frontmatter, raw_markdown)
+                    } else {
+                        (PageFrontmatter::default(), raw_content.as_str())
+                    };
+
+                let html_content = markdown_to_html(raw_markdown, &path)?;
+                let markdown_start = offset_in(&raw_content, raw_markdown);
+
+                pages.insert(
+                    page_name,
+                    Page {
+                        frontmatter,
+                        html_content,
+                        raw_content,
+                        markdown_start,
+                    },
+                );
 
                 info!(?path, "loaded static page");
             };
 
+            report_unknown_languages(&path);
+
+            Span::current().record("duration_ms", elapsed_ms(page_start));
             Ok::<_, LoadStateError>((groups, tags, pages, posts))
         };
 
-        let mut top_level_reader = fs::read_dir(&self.content_path).await.map_err(ReadDir)?;
-        while let Some(entry) = top_level_reader.next_entry().await.map_err(ReadDirEntry)? {
-            if entry.metadata().await.map_err(DirEntryMetadata)?.is_file() {
-                (groups, tags, pages, posts) =
-                    load_page(entry, GroupName::Root, groups, tags, pages, posts).await?;
-            } else {
-                let group_name = entry
-                    .file_name()
-                    .to_str()
-                    .ok_or_else(|| PathInvalidUtf8(entry.path()))?
-                    .to_string();
-                let group: GroupName = group_name.try_into()?;
-                groups.insert(group.clone(), <_>::default());
-                groups_to_load.push((entry.path(), group));
+        let mut site_redirects = HashMap::<String, String>::new();
+        let mut gone = GoneFile::default();
+
+        let content_path = &self.content_path;
+        (groups, tags, pages, posts) = {
+            // The maps are moved into the walk and handed back, but these are
+            // only borrowed.
+            let site_redirects = &mut site_redirects;
+            let gone = &mut gone;
+            let groups_to_load = &mut groups_to_load;
+            async move {
+                let mut top_level_reader = fs::read_dir(content_path).await.map_err(ReadDir)?;
+                while let Some(entry) = top_level_reader.next_entry().await.map_err(ReadDirEntry)? {
+                    if entry.file_name() == REDIRECTS_FILE_NAME {
+                        let raw_redirects = fs::read_to_string(entry.path())
+                            .await
+                            .map_err(ReadRedirects)?;
+                        *site_redirects = toml::from_str(&raw_redirects).map_err(ParseRedirects)?;
+                        info!(path = ?entry.path(), "loaded site redirects");
+                    } else if entry.file_name() == GONE_FILE_NAME {
+                        let raw_gone = fs::read_to_string(entry.path()).await.map_err(ReadGone)?;
+                        *gone = toml::from_str(&raw_gone).map_err(ParseGone)?;
+                        info!(path = ?entry.path(), "loaded gone paths");
+                    } else if entry.metadata().await.map_err(DirEntryMetadata)?.is_file() {
+                        let span = page_span(&entry.path());
+                        (groups, tags, pages, posts) =
+                            load_page(entry, GroupName::Root, groups, tags, pages, posts)
+                                .instrument(span)
+                                .await?;
+                    } else {
+                        let group_name = entry
+                            .file_name()
+                            .to_str()
+                            .ok_or_else(|| PathInvalidUtf8(entry.path()))?
+                            .to_string();
+                        let group: GroupName = group_name.try_into()?;
+                        groups.insert(group.clone(), <_>::default());
+                        groups_to_load.push((entry.path(), group));
+                    }
+                }
+
+                Ok::<_, LoadStateError>((groups, tags, pages, posts))
             }
+            .instrument(info_span!("walk_dir", path = %content_path))
         }
+        .await?;
 
         for (group_path, group) in groups_to_load {
-            let mut group_reader = fs::read_dir(group_path).await.map_err(ReadDir)?;
+            let span = info_span!("load_group", %group, path = %group_path.display());
+            (groups, tags, pages, posts) = async move {
+                let mut group_reader = fs::read_dir(&group_path).await.map_err(ReadDir)?;
+
+                while let Some(entry) = group_reader.next_entry().await.map_err(ReadDirEntry)? {
+                    if entry.metadata().await.map_err(DirEntryMetadata)?.is_file() {
+                        let span = page_span(&entry.path());
+                        (groups, tags, pages, posts) =
+                            load_page(entry, group.clone(), groups, tags, pages, posts)
+                                .instrument(span)
+                                .await?;
+                    } else {
+                        info!(path = ?entry.path(), "skipping nested group");
+                    }
+                }
 
-            while let Some(entry) = group_reader.next_entry().await.map_err(ReadDirEntry)? {
-                if entry.metadata().await.map_err(DirEntryMetadata)?.is_file() {
-                    (groups, tags, pages, posts) =
-                        load_page(entry, group.clone(), groups, tags, pages, posts).await?;
-                } else {
-                    info!(path = ?entry.path(), "skipping nested group");
+                Ok::<_, LoadStateError>((groups, tags, pages, posts))
+            }
+            .instrument(span)
+            .await?;
+        }
+
+        // Generating image variants means decoding and resizing images, which
+        // can take seconds, so it's kept off the runtime's worker threads.
+        let images = ImageProcessor::new(self.static_path.clone(), self.image_cache_path.clone());
+        let span = info_span!("process_images");
+        (pages, posts) = task::spawn_blocking(move || {
+            span.in_scope(|| {
+                let images = images?;
+                for page in pages.values_mut() {
+                    page.html_content = images.rewrite_images(&page.html_content)?;
+                }
+                for post in posts.values_mut() {
+                    post.html_content = images.rewrite_images(&post.html_content)?;
+                    post.summary_html = images.rewrite_images(&post.summary_html)?;
+                }
+                Ok::<_, ProcessImageError>((pages, posts))
+            })
+        })
+        .await
+        .expect("processing images should not panic")?;
+
+        let mut redirects = Redirects::default();
+        let mut routes = HashSet::from(["/".to_owned()]);
+
+        for (from, to) in site_redirects {
+            redirects.insert(&from, &to)?;
+        }
+
+        for path in &gone.paths {
+            redirects.insert_gone(path);
+        }
+
+        for (group_name, group) in &groups {
+            let group_route = group_name.route();
+
+            if let Some(page) = group.index.as_ref().and_then(|index| pages.get(index)) {
+                for alias in &page.frontmatter.aliases {
+                    redirects.insert(alias, &group_route)?;
+                }
+            }
+
+            for (post_name, post) in group
+                .members
+                .iter()
+                .filter_map(|name| posts.get(name).map(|post| (name, post)))
+            {
+                let post_route = group_name.post_route(post_name);
+                for alias in &post.frontmatter.aliases {
+                    redirects.insert(alias, &post_route)?;
                 }
+                routes.insert(post_route);
             }
+
+            routes.insert(group_route);
         }
 
+        routes.extend(tags.keys().map(|tag_name| format!("/tagged/{tag_name}")));
+        redirects.check_collisions(&routes)?;
+
+        let content_counts = groups.iter().flat_map(|(group_name, group)| {
+            let group_route = group_name.route();
+            let loaded = |loaded: &dyn Fn(&PageName) -> bool| {
+                group.members.iter().filter(|name| loaded(name)).count()
+            };
+
+            // Drafts that were skipped are still members of their group, but
+            // were never loaded.
+            let group_posts = loaded(&|name| posts.contains_key(name));
+            let group_pages = loaded(&|name| pages.contains_key(name))
+                + usize::from(
+                    group
+                        .index
+                        .as_ref()
+                        .is_some_and(|index| pages.contains_key(index)),
+                );
+            let group_tags = tags
+                .values()
+                .filter(|tag| !tag.members.is_disjoint(&group.members))
+                .count();
+
+            [
+                (group_route.clone(), "posts", group_posts),
+                (group_route.clone(), "pages", group_pages),
+                (group_route, "tags", group_tags),
+            ]
+        });
+        let content_counts = content_counts.collect();
+
         let groups = Arc::new(groups);
         let tags = Arc::new(tags);
         let pages = Arc::new(pages);
         let posts = Arc::new(posts);
+        let redirects = Arc::new(redirects);
+        let mut routes = routes.into_iter().collect::<Vec<_>>();
+        routes.sort();
         let content = Content {
             groups,
             tags,
             pages,
             posts,
+            redirects,
+            routes: Arc::new(routes),
         };
 
-        Ok(State { content, theme })
+        let canonical = Canonical::new(self.canonical_url)?;
+
+        let style_sources = style_sources
+            .into_inner()
+            .expect("lock should not be poisoned");
+        let security_headers =
+            SecurityHeaders::new(&self.security, &style_sources).map_err(SecurityHeader)?;
+
+        let state = State {
+            content,
+            theme,
+            canonical,
+            security_headers,
+            metrics,
+        };
+        let stats = LoadStats {
+            content: content_counts,
+            duration: load_start.elapsed(),
+        };
+        Ok((state, stats))
     }
 }
 
+/// The span a page is loaded in, which records its size and how long it took
+/// once it's been loaded.
+fn page_span(path: &Path) -> Span {
+    info_span!(
+        "load_page",
+        path = %path.display(),
+        bytes = field::Empty,
+        duration_ms = field::Empty,
+    )
+}
+
+/// The options markdown is parsed and rendered with, which plain text is
+/// rendered with too.
+fn markdown_options() -> ComrakOptions {
+    let mut options = ComrakOptions::default();
+    // The code block adapter needs the whole info string of each code fence,
+    // which comrak only passes to it in this mode.
+    options.render.github_pre_lang = true;
+    options
+}
+
+/// Where `part` starts in `whole`, which it has to have been sliced from.
+fn offset_in(whole: &str, part: &str) -> usize {
+    let offset = part.as_ptr() as usize - whole.as_ptr() as usize;
+    debug_assert!(whole.get(offset..offset + part.len()) == Some(part));
+    offset
+}
+
+/// The time since `start` in milliseconds, for recording on spans.
+fn elapsed_ms(start: Instant) -> f64 {
+    start.elapsed().as_secs_f64() * 1000.0
+}
+
 #[derive(Error, Debug)]
 pub enum LoadStateError {
     #[error("failed to load theme set: {0}")]
@@ -231,6 +574,9 @@ pub enum LoadStateError {
     #[error(transparent)]
     LoadThemeError(#[from] LoadThemeError),
 
+    #[error("failed to load syntax set: {0}")]
+    LoadSyntaxSet(#[source] SyntectLoadingError),
+
     #[error("failed to read contents of dir: {0}")]
     ReadDir(#[source] io::Error),
 
@@ -266,12 +612,45 @@ pub enum LoadStateError {
 
     #[error("failed to parse page frontmatter: {0}")]
     ParseFrontmatter(#[from] toml::de::Error),
+
+    #[error("failed to render markdown of page at path {0}: {1}")]
+    RenderMarkdown(PathBuf, #[source] io::Error),
+
+    #[error("failed to render math in page at path {0}: {1}")]
+    RenderMath(PathBuf, #[source] RenderMathError),
+
+    #[error(transparent)]
+    ProcessImage(#[from] ProcessImageError),
+
+    #[error("failed to read site redirects: {0}")]
+    ReadRedirects(#[source] io::Error),
+
+    #[error("failed to parse site redirects: {0}")]
+    ParseRedirects(#[source] toml::de::Error),
+
+    #[error("failed to read gone paths: {0}")]
+    ReadGone(#[source] io::Error),
+
+    #[error("failed to parse gone paths: {0}")]
+    ParseGone(#[source] toml::de::Error),
+
+    #[error(transparent)]
+    Redirect(#[from] RedirectError),
+
+    #[error(transparent)]
+    InvalidCanonicalUrl(#[from] InvalidCanonicalUrlError),
+
+    #[error("security header value is invalid: {0}")]
+    SecurityHeader(#[source] InvalidHeaderValue),
 }
 
 #[derive(Clone, Debug)]
 pub struct State {
     pub content: Content,
     pub theme: Theme,
+    pub canonical: Canonical,
+    pub security_headers: SecurityHeaders,
+    pub metrics: SiteMetrics,
 }
 
 type PostName = PageName;
@@ -287,12 +666,17 @@ pub struct Content {
     tags: Arc<TagsMap>,
     pages: Arc<PagesMap>,
     posts: Arc<PostsMap>,
+    redirects: Arc<Redirects>,
+    /// Every route served from content, for suggesting when a path doesn't
+    /// exist.
+    routes: Arc<Vec<String>>,
 }
 
 impl Content {
     pub fn group(&self, group_name: &GroupName) -> Option<GroupRef<'_>> {
         self.groups.get(group_name).map(|group| GroupRef {
             group,
+            name: group_name.clone(),
             content: self,
         })
     }
@@ -313,6 +697,40 @@ impl Content {
             content: self,
         })
     }
+
+    pub fn redirect_target(&self, path: &str) -> Option<&Uri> {
+        self.redirects.target(path)
+    }
+
+    pub fn is_gone(&self, path: &str) -> bool {
+        self.redirects.is_gone(path)
+    }
+
+    /// Finds the routes closest to a path that doesn't exist, best first.
+    pub fn suggestions(&self, path: &str) -> Vec<&str> {
+        suggestions::closest(path, &self.routes)
+    }
+
+    /// Finds the error page authored in content to show for a request path,
+    /// if there is one.
+    ///
+    /// A page in the group the path is in takes precedence over one in the
+    /// root of the content dir.
+    pub fn error_page(&self, kind: ErrorPageKind, path: &str) -> Option<&Page> {
+        let path_group = path
+            .trim_start_matches('/')
+            .split('/')
+            .next()
+            .filter(|segment| !segment.is_empty())
+            .and_then(|segment| GroupName::try_from(segment.to_owned()).ok());
+
+        path_group
+            .into_iter()
+            .chain([GroupName::Root])
+            .filter_map(|group_name| self.groups.get(&group_name))
+            .find_map(|group| group.error_pages.get(&kind))
+            .and_then(|page_name| self.pages.get(page_name))
+    }
 }
 
 impl FromRef<State> for Content {
@@ -325,6 +743,27 @@ impl FromRef<State> for Content {
 pub struct Group {
     index: Option<PageName>,
     members: HashSet<PageName>,
+    error_pages: HashMap<ErrorPageKind, PageName>,
+}
+
+/// The error pages that can be authored in content, to be shown instead of
+/// the built-in ones.
+#[derive(Clone, Copy, Debug, Hash, PartialEq, Eq)]
+pub enum ErrorPageKind {
+    NotFound,
+    InternalError,
+}
+
+impl ErrorPageKind {
+    /// Works out which error page a content file is, from its file stem, if
+    /// it's one at all.
+    fn from_file_stem(file_stem: &str) -> Option<Self> {
+        match file_stem {
+            "_404" => Some(Self::NotFound),
+            "_500" => Some(Self::InternalError),
+            _ => None,
+        }
+    }
 }
 
 #[derive(Clone, Debug, Default)]
@@ -336,12 +775,49 @@ pub struct Tag {
 pub struct Post {
     pub date: NaiveDate,
     pub frontmatter: PostFrontmatter,
+    pub title: Option<String>,
     pub html_content: String,
+    pub summary_html: String,
+    pub word_count: usize,
+    /// The whole file the post was loaded from, including its frontmatter.
+    pub raw_content: String,
+    /// Where the markdown starts in `raw_content`, after the frontmatter.
+    markdown_start: usize,
+}
+
+impl Post {
+    /// The estimated time to read the post, in minutes.
+    pub fn reading_time(&self) -> usize {
+        summary::reading_time(self.word_count)
+    }
+
+    /// The post rendered as plain text.
+    pub fn text(&self) -> String {
+        text::markdown_to_text(
+            &self.raw_content[self.markdown_start..],
+            &markdown_options(),
+        )
+    }
 }
 
 #[derive(Clone, Debug)]
 pub struct Page {
+    pub frontmatter: PageFrontmatter,
     pub html_content: String,
+    /// The whole file the page was loaded from, including any frontmatter.
+    pub raw_content: String,
+    /// Where the markdown starts in `raw_content`, after any frontmatter.
+    markdown_start: usize,
+}
+
+impl Page {
+    /// The page rendered as plain text.
+    pub fn text(&self) -> String {
+        text::markdown_to_text(
+            &self.raw_content[self.markdown_start..],
+            &markdown_options(),
+        )
+    }
 }
 
 #[derive(Clone, Debug, Deserialize)]
@@ -351,11 +827,32 @@ pub struct PostFrontmatter {
 
     #[serde(default)]
     tags: Vec<TagName>,
+
+    /// A summary of the post, written in markdown, used in listings when the
+    /// post doesn't contain a `<!-- more -->` marker.
+    #[serde(default)]
+    summary: Option<String>,
+
+    /// Old paths that should redirect to this post.
+    #[serde(default)]
+    aliases: Vec<String>,
+}
+
+#[derive(Clone, Debug, Default, Deserialize)]
+pub struct PageFrontmatter {
+    /// Old paths that should redirect to this page's group.
+    #[serde(default)]
+    aliases: Vec<String>,
 }
 
 #[derive(Clone, Debug)]
 pub struct Theme {
     theme_header: Markup,
+    /// The CSP source that allows the inline `<style>` holding the theme
+    /// header.
+    style_source: String,
+    /// The SRI hash of [`STYLESHEET`].
+    stylesheet_integrity: String,
 }
 
 impl Theme {
@@ -366,31 +863,35 @@ impl Theme {
     ) -> Result<Self, LoadThemeError> {
         use LoadThemeError::*;
 
-        let light_css = css_for_theme_with_class_style(
-            theme_set
-                .themes
-                .get(light)
-                .ok_or_else(|| MissingTheme(light))?,
-            ClassStyle::Spaced,
-        )
-        .map_err(GenerateThemeCss)?;
-        let light_block = format!(":root {{ {light_css} }}");
-
-        let dark_css = css_for_theme_with_class_style(
-            theme_set
-                .themes
-                .get(dark)
-                .ok_or_else(|| MissingTheme(dark))?,
-            ClassStyle::Spaced,
-        )
-        .map_err(GenerateThemeCss)?;
-        let dark_block = format!("@media(prefers-color-scheme: dark) {{ :root{{ {dark_css} }} }}");
+        let light_theme = theme_set
+            .themes
+            .get(light)
+            .ok_or_else(|| MissingTheme(light))?;
+        let light_css = css_for_theme_with_class_style(light_theme, ClassStyle::Spaced)
+            .map_err(GenerateThemeCss)?;
+        let light_code_block_css = highlight::code_block_css(light_theme);
+        let light_block = format!(":root {{ {light_css} {light_code_block_css} }}");
+
+        let dark_theme = theme_set
+            .themes
+            .get(dark)
+            .ok_or_else(|| MissingTheme(dark))?;
+        let dark_css = css_for_theme_with_class_style(dark_theme, ClassStyle::Spaced)
+            .map_err(GenerateThemeCss)?;
+        let dark_code_block_css = highlight::code_block_css(dark_theme);
+        let dark_block = format!(
+            "@media(prefers-color-scheme: dark) {{ :root{{ {dark_css} {dark_code_block_css} }} }}"
+        );
+
+        let theme_header = html! {
+            (PreEscaped(light_block))
+            (PreEscaped(dark_block))
+        };
 
         Ok(Self {
-            theme_header: html! {
-                (PreEscaped(light_block))
-                (PreEscaped(dark_block))
-            },
+            style_source: csp_hash_source(&theme_header.0),
+            stylesheet_integrity: sri_hash(STYLESHEET.as_bytes()),
+            theme_header,
         })
     }
 }
@@ -408,6 +909,10 @@ impl Theme {
     pub fn theme_header(&self) -> &Markup {
         &self.theme_header
     }
+
+    pub fn stylesheet_integrity(&self) -> &str {
+        &self.stylesheet_integrity
+    }
 }
 
 impl FromRef<State> for Theme {
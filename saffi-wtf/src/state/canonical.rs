@@ -0,0 +1,73 @@
+use axum::{
+    extract::FromRef,
+    http::{
+        uri::{Authority, Scheme},
+        Uri,
+    },
+};
+use thiserror::Error;
+
+use crate::state::State;
+
+/// Where the site is canonically served from, used to build canonical URLs
+/// and to redirect requests that arrive on any other host.
+///
+/// If no canonical URL is configured, canonical URLs are just paths, and
+/// requests are accepted on any host.
+#[derive(Clone, Debug, Default)]
+pub struct Canonical {
+    origin: Option<(Scheme, Authority)>,
+}
+
+impl Canonical {
+    pub fn new(url: Option<Uri>) -> Result<Self, InvalidCanonicalUrlError> {
+        let origin = url
+            .map(|url| {
+                let (scheme, authority) = (url.scheme().cloned(), url.authority().cloned());
+                scheme
+                    .zip(authority)
+                    .filter(|_| matches!(url.path(), "" | "/") && url.query().is_none())
+                    .ok_or(InvalidCanonicalUrlError(url))
+            })
+            .transpose()?;
+
+        Ok(Self { origin })
+    }
+
+    /// The canonical host (and port, if it has one), if one is configured.
+    pub fn authority(&self) -> Option<&Authority> {
+        self.origin.as_ref().map(|(_, authority)| authority)
+    }
+
+    /// Builds the canonical URL for a path, which should already be in its
+    /// canonical form.
+    pub fn url(&self, path: &str) -> String {
+        match &self.origin {
+            Some((scheme, authority)) => format!("{scheme}://{authority}{path}"),
+            None => path.to_owned(),
+        }
+    }
+}
+
+impl FromRef<State> for Canonical {
+    fn from_ref(input: &State) -> Self {
+        input.canonical.clone()
+    }
+}
+
+#[derive(Error, Debug)]
+#[error("canonical URL must be just a scheme and host, like https://example.com, not {0}")]
+pub struct InvalidCanonicalUrlError(Uri);
+
+/// Puts a path into its canonical form, with a single leading slash and
+/// without a trailing slash (unless it's the root).
+///
+/// A path starting with more than one slash, like `//example.com`, would be
+/// taken by browsers as a link to another host, so the extras are dropped.
+pub fn canonical_path(path: &str) -> &str {
+    let extra_slashes = (path.len() - path.trim_start_matches('/').len()).saturating_sub(1);
+    match path[extra_slashes..].trim_end_matches('/') {
+        "" => "/",
+        trimmed => trimmed,
+    }
+}
@@ -0,0 +1,155 @@
+use std::{cell::RefCell, panic, path::Path};
+
+use comrak::{
+    arena_tree::Node,
+    nodes::{Ast, AstNode, NodeValue},
+    Arena,
+};
+use svgbob::Settings;
+use tracing::warn;
+use uuid::Uuid;
+
+/// The code fence languages that are rendered as ASCII diagrams.
+const DIAGRAM_LANGS: &[&str] = &["svgbob", "bob"];
+
+/// Diagrams that have been rendered to SVG from code blocks in a document, to
+/// be put into the HTML once the document has been rendered.
+///
+/// Raw HTML in markdown isn't let through, so rather than put the SVG in the
+/// document as an HTML block, each diagram's code block is swapped for a
+/// paragraph holding an alphanumeric placeholder.
+pub struct RenderedDiagrams {
+    placeholder_prefix: String,
+    rendered: Vec<String>,
+}
+
+impl RenderedDiagrams {
+    /// Renders every code block tagged as an ASCII diagram to an inline SVG,
+    /// replacing the code block with a placeholder.
+    ///
+    /// The SVGs are coloured with the same CSS variables as the rest of the
+    /// page, so they follow the light/dark theme. If a diagram can't be
+    /// rendered, it's left as a plain code block.
+    pub fn render<'a>(arena: &'a Arena<AstNode<'a>>, root: &'a AstNode<'a>, path: &Path) -> Self {
+        let settings = Settings {
+            font_family: "'Iosevka', monospace".into(),
+            fill_color: "var(--text)".into(),
+            background: "var(--bg)".into(),
+            stroke_color: "var(--text)".into(),
+            include_backdrop: false,
+            ..Settings::default()
+        };
+
+        let mut diagrams = Self {
+            placeholder_prefix: format!("diagram{}n", Uuid::new_v4().simple()),
+            rendered: Vec::new(),
+        };
+
+        for node in root.descendants().collect::<Vec<_>>() {
+            let mut data = node.data.borrow_mut();
+            let NodeValue::CodeBlock(code_block) = &mut data.value else {
+                continue;
+            };
+
+            let lang = code_block
+                .info
+                .split(|c: char| c == ',' || c.is_whitespace())
+                .next()
+                .unwrap_or_default();
+            if !DIAGRAM_LANGS.contains(&lang) {
+                continue;
+            }
+
+            // svgbob doesn't report errors, but it can panic on input it
+            // doesn't expect, which shouldn't take the whole site down with
+            // it.
+            let ascii = code_block.literal.as_str();
+            match panic::catch_unwind(|| svgbob::to_svg_with_settings(ascii, &settings)) {
+                Ok(svg) => {
+                    diagrams
+                        .rendered
+                        .push(format!("<figure class=\"diagram\">{svg}</figure>"));
+                    let placeholder = diagrams.placeholder(diagrams.rendered.len() - 1);
+
+                    data.value = NodeValue::Paragraph;
+                    let start = data.sourcepos.start;
+                    drop(data);
+                    node.append(arena.alloc(Node::new(RefCell::new(Ast::new(
+                        NodeValue::Text(placeholder),
+                        start,
+                    )))));
+                }
+                Err(_) => {
+                    warn!(
+                        ?path,
+                        "failed to render diagram, falling back to a code block"
+                    );
+                    code_block.info.clear();
+                }
+            }
+        }
+
+        diagrams
+    }
+
+    /// Puts the rendered diagrams into the HTML rendered from the document
+    /// passed to [`render()`](Self::render).
+    pub fn restore(&self, html: &str) -> String {
+        let mut html = html.to_owned();
+
+        for (index, svg) in self.rendered.iter().enumerate() {
+            let placeholder = self.placeholder(index);
+
+            // Paragraphs in tight lists are rendered without `<p>` tags, so
+            // the placeholder can be on its own too.
+            html = html.replace(&format!("<p>{placeholder}</p>"), svg);
+            html = html.replace(&placeholder, svg);
+        }
+
+        html
+    }
+
+    fn placeholder(&self, index: usize) -> String {
+        format!("{}{index}end", self.placeholder_prefix)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use comrak::{format_html, parse_document, ComrakOptions};
+
+    use super::*;
+
+    fn render_html(markdown: &str) -> String {
+        let arena = Arena::new();
+        let options = ComrakOptions::default();
+        let root = parse_document(&arena, markdown, &options);
+        let diagrams = RenderedDiagrams::render(&arena, root, Path::new("test.md"));
+
+        let mut html = Vec::new();
+        format_html(root, &options, &mut html).unwrap();
+        let html = diagrams.restore(&String::from_utf8(html).unwrap());
+
+        assert!(
+            !html.contains(&diagrams.placeholder_prefix),
+            "placeholder left in {html}"
+        );
+        html
+    }
+
+    #[test]
+    fn replaces_diagrams_with_figures() {
+        let html = render_html("```svgbob\n+--+\n|  |\n+--+\n```\n");
+        assert!(html.starts_with("<figure class=\"diagram\"><svg"), "{html}");
+        assert!(!html.contains("<p>"), "{html}");
+    }
+
+    #[test]
+    fn replaces_diagrams_in_tight_lists() {
+        let html = render_html("- before\n- ```svgbob\n  +--+\n  ```\n- after\n");
+        assert!(
+            html.contains("<li><figure class=\"diagram\"><svg"),
+            "{html}"
+        );
+    }
+}
@@ -0,0 +1,287 @@
+use std::{
+    collections::{BTreeSet, HashMap},
+    fmt::Write as _,
+    io::{self, Write},
+    mem,
+    ops::RangeInclusive,
+    sync::Mutex,
+};
+
+use comrak::adapters::SyntaxHighlighterAdapter;
+use maud::html;
+use syntect::{
+    highlighting::{Color, Theme as SyntectTheme},
+    html::{line_tokens_to_classed_spans, ClassStyle},
+    parsing::{ParseState, ScopeStack, SyntaxReference, SyntaxSet},
+    util::LinesWithEndings,
+    Error as SyntectError,
+};
+use tracing::warn;
+
+/// The prefix of a code fence language that turns on diff highlighting, e.g.
+/// `diff-rust`.
+const DIFF_PREFIX: &str = "diff-";
+
+/// Highlights fenced code blocks, with support for attributes in the fence's
+/// info string.
+///
+/// The info string is a comma-separated list, starting with the language and
+/// followed by any of:
+///
+/// - `file=<name>`, to add a caption with the name of the file;
+/// - `hl_lines=<ranges>`, to highlight lines, where ranges are either single
+///   line numbers or inclusive ranges like `3-5`, and more than one can be
+///   given as extra comma-separated items (e.g. `hl_lines=1,3-5`);
+/// - `linenos`, to show line numbers.
+///
+/// A language of the form `diff-<lang>` treats each line as a line of a diff,
+/// styling it based on its `+`/`-` prefix, and highlights the rest of it as
+/// `<lang>`.
+///
+/// Everything is styled with classes rather than inline styles, so the colours
+/// come from the CSS generated by [`Theme`](super::Theme).
+pub struct CodeBlockAdapter {
+    syntax_set: SyntaxSet,
+    /// Languages named by code blocks that aren't in the syntax set, recorded
+    /// so they can be reported once for the page they're in.
+    unknown_languages: Mutex<BTreeSet<String>>,
+}
+
+impl CodeBlockAdapter {
+    pub fn new(syntax_set: SyntaxSet) -> Self {
+        Self {
+            syntax_set,
+            unknown_languages: Mutex::default(),
+        }
+    }
+
+    /// Returns the languages with no known syntax that have been encountered
+    /// since this was last called, without duplicates.
+    pub fn take_unknown_languages(&self) -> BTreeSet<String> {
+        let mut unknown_languages = self
+            .unknown_languages
+            .lock()
+            .expect("lock should not be poisoned");
+        mem::take(&mut *unknown_languages)
+    }
+
+    fn find_syntax(&self, lang: Option<&str>, code: &str) -> &SyntaxReference {
+        let lang = lang.filter(|lang| !lang.is_empty());
+        let syntax = lang.and_then(|lang| self.syntax_set.find_syntax_by_token(lang));
+
+        if let (Some(lang), None) = (lang, syntax) {
+            self.unknown_languages
+                .lock()
+                .expect("lock should not be poisoned")
+                .insert(lang.to_owned());
+        }
+
+        syntax
+            .or_else(|| self.syntax_set.find_syntax_by_first_line(code))
+            .unwrap_or_else(|| self.syntax_set.find_syntax_plain_text())
+    }
+
+    fn highlight(&self, info: &FenceInfo<'_>, code: &str) -> Result<String, SyntectError> {
+        let syntax = self.find_syntax(info.lang, code);
+        let mut parse_state = ParseState::new(syntax);
+        let mut scope_stack = ScopeStack::new();
+        let mut output = String::with_capacity(code.len() * 2);
+
+        for (index, line) in LinesWithEndings::from(code).enumerate() {
+            let number = index + 1;
+
+            let (diff_class, diff_marker, line) = if info.diff {
+                match line.chars().next() {
+                    Some('+') => (Some("diff-add"), Some("+"), &line[1..]),
+                    Some('-') => (Some("diff-del"), Some("-"), &line[1..]),
+                    Some(' ') => (None, Some(" "), &line[1..]),
+                    _ => (None, Some(" "), line),
+                }
+            } else {
+                (None, None, line)
+            };
+
+            output.push_str("<span class=\"line");
+            if info.is_highlighted(number) {
+                output.push_str(" hl");
+            }
+            if let Some(diff_class) = diff_class {
+                output.push(' ');
+                output.push_str(diff_class);
+            }
+            output.push_str("\">");
+
+            if info.line_numbers {
+                write!(output, "<span class=\"ln\">{number}</span>")?;
+            }
+            if let Some(diff_marker) = diff_marker {
+                write!(output, "<span class=\"diff-marker\">{diff_marker}</span>")?;
+            }
+
+            // Each line is wrapped in its own span, so any scopes that were
+            // left open at the end of the previous line need to be reopened
+            // here, and closed again at the end of this one.
+            for scope in scope_stack.as_slice() {
+                write!(
+                    output,
+                    "<span class=\"{}\">",
+                    scope.build_string().replace('.', " ")
+                )?;
+            }
+
+            let ops = parse_state.parse_line(line, &self.syntax_set)?;
+            let (html, _) =
+                line_tokens_to_classed_spans(line, &ops, ClassStyle::Spaced, &mut scope_stack)?;
+            output.push_str(&html);
+
+            for _ in scope_stack.as_slice() {
+                output.push_str("</span>");
+            }
+            output.push_str("</span>");
+        }
+
+        Ok(output)
+    }
+}
+
+impl SyntaxHighlighterAdapter for CodeBlockAdapter {
+    fn write_highlighted(
+        &self,
+        output: &mut dyn Write,
+        lang: Option<&str>,
+        code: &str,
+    ) -> io::Result<()> {
+        let info = FenceInfo::parse(lang.unwrap_or_default());
+        for attribute in &info.ignored {
+            warn!(lang = ?info.lang, attribute, "ignoring malformed code block attribute");
+        }
+
+        match self.highlight(&info, code) {
+            Ok(highlighted) => output.write_all(highlighted.as_bytes()),
+            Err(error) => {
+                warn!(lang = ?info.lang, %error, "failed to highlight code block");
+                output.write_all(html! { (code) }.into_string().as_bytes())
+            }
+        }
+    }
+
+    /// Relies on comrak's `github_pre_lang` option being set, so that the info
+    /// string is passed through in the `lang` attribute.
+    fn write_pre_tag(
+        &self,
+        output: &mut dyn Write,
+        attributes: HashMap<String, String>,
+    ) -> io::Result<()> {
+        let info = FenceInfo::parse(attributes.get("lang").map_or("", String::as_str));
+        let class = if info.line_numbers {
+            "syntax-highlighting linenos"
+        } else {
+            "syntax-highlighting"
+        };
+
+        // Only the opening tag (and the caption) are written here; comrak
+        // writes the closing tag itself.
+        let mut tag = format!("<pre class=\"{class}\"");
+        if let Some(lang) = info.lang {
+            write!(tag, " data-lang=\"{}\"", html! { (lang) }.into_string())
+                .map_err(io::Error::other)?;
+        }
+        tag.push('>');
+        if let Some(file) = info.file {
+            tag.push_str(&html! { span class="caption" { (file) } }.into_string());
+        }
+
+        output.write_all(tag.as_bytes())
+    }
+
+    fn write_code_tag(
+        &self,
+        output: &mut dyn Write,
+        _attributes: HashMap<String, String>,
+    ) -> io::Result<()> {
+        output.write_all(b"<code>")
+    }
+}
+
+/// The parsed info string of a fenced code block.
+#[derive(Debug, Default)]
+struct FenceInfo<'a> {
+    lang: Option<&'a str>,
+    diff: bool,
+    file: Option<&'a str>,
+    highlighted: Vec<RangeInclusive<usize>>,
+    line_numbers: bool,
+    /// Any attributes (or highlighted line ranges) that couldn't be parsed.
+    ignored: Vec<&'a str>,
+}
+
+impl<'a> FenceInfo<'a> {
+    fn parse(info: &'a str) -> Self {
+        let mut fence_info = Self::default();
+        let mut parts = info.split(',').map(str::trim);
+
+        if let Some(lang) = parts.next().filter(|lang| !lang.is_empty()) {
+            fence_info.diff = lang.starts_with(DIFF_PREFIX);
+            fence_info.lang = Some(lang.strip_prefix(DIFF_PREFIX).unwrap_or(lang));
+        }
+
+        // Whether the previous attribute was `hl_lines`, in which case bare
+        // ranges continue its list.
+        let mut in_hl_lines = false;
+
+        for part in parts.filter(|part| !part.is_empty()) {
+            match part.split_once('=') {
+                Some(("file", file)) => {
+                    in_hl_lines = false;
+                    fence_info.file = Some(file);
+                }
+                Some(("hl_lines", range)) => {
+                    in_hl_lines = true;
+                    fence_info.push_range(range);
+                }
+                None if part == "linenos" => {
+                    in_hl_lines = false;
+                    fence_info.line_numbers = true;
+                }
+                None if in_hl_lines => fence_info.push_range(part),
+                _ => fence_info.ignored.push(part),
+            }
+        }
+
+        fence_info
+    }
+
+    fn push_range(&mut self, raw: &'a str) {
+        let range = match raw.split_once('-') {
+            Some((start, end)) => start.parse().ok().zip(end.parse().ok()),
+            None => raw.parse().ok().map(|line| (line, line)),
+        };
+
+        match range {
+            Some((start, end)) => self.highlighted.push(start..=end),
+            None => self.ignored.push(raw),
+        }
+    }
+
+    fn is_highlighted(&self, line: usize) -> bool {
+        self.highlighted.iter().any(|range| range.contains(&line))
+    }
+}
+
+/// Generates the CSS for the parts of code blocks that are styled from the
+/// theme, rather than by syntax scope.
+pub fn code_block_css(theme: &SyntectTheme) -> String {
+    let hex = |Color { r, g, b, a }: Color| format!("#{r:02x}{g:02x}{b:02x}{a:02x}");
+
+    let mut css = String::new();
+    if let Some(line_highlight) = theme.settings.line_highlight {
+        css.push_str(&format!(
+            ".line.hl {{ background-color: {}; }}",
+            hex(line_highlight)
+        ));
+    }
+    if let Some(gutter_foreground) = theme.settings.gutter_foreground {
+        css.push_str(&format!(".ln {{ color: {}; }}", hex(gutter_foreground)));
+    }
+    css
+}
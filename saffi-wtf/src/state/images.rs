@@ -0,0 +1,367 @@
+use std::{fs, io, time::SystemTime};
+
+use camino::{Utf8Component, Utf8Path, Utf8PathBuf};
+use image::{imageops::FilterType, DynamicImage, ImageError, ImageFormat};
+use maud::{html, PreEscaped};
+use thiserror::Error;
+use tracing::{info, warn};
+
+/// The URL prefix that files in the static dir are served under.
+const STATIC_PREFIX: &str = "/static/";
+
+/// The URL prefix that resized image variants are served under.
+pub const IMAGES_PREFIX: &str = "/images/";
+
+/// The widths that resized variants of each image are generated at. Images are
+/// never scaled up, so only the widths smaller than the original are used.
+const VARIANT_WIDTHS: &[u32] = &[480, 960, 1440];
+
+/// How wide images are displayed at various viewport widths; this needs to
+/// match the `max-width` and padding of `body` in the stylesheet.
+const SIZES: &str = "(max-width: 44rem) calc(100vw - 2rem), 42rem";
+
+/// Generates resized and WebP variants of images referenced from rendered
+/// markdown, and rewrites the `<img>` tags that reference them into responsive
+/// markup.
+///
+/// Without a cache dir to generate variants in, images only get their
+/// dimensions added.
+#[derive(Clone, Debug)]
+pub struct ImageProcessor {
+    static_path: Utf8PathBuf,
+    cache_path: Option<Utf8PathBuf>,
+}
+
+impl ImageProcessor {
+    pub fn new(
+        static_path: Utf8PathBuf,
+        cache_path: Option<Utf8PathBuf>,
+    ) -> Result<Self, ProcessImageError> {
+        if let Some(cache_path) = &cache_path {
+            fs::create_dir_all(cache_path)
+                .map_err(|err| ProcessImageError::CreateDir(cache_path.clone(), err))?;
+        }
+
+        Ok(Self {
+            static_path,
+            cache_path,
+        })
+    }
+
+    /// Rewrites every `<img>` tag in the given HTML.
+    ///
+    /// Images served from the static dir get `srcset` and `sizes` attributes
+    /// pointing at their resized variants, a WebP `<source>`, and explicit
+    /// dimensions (or just the dimensions, if there's no cache dir). All
+    /// images are marked as lazy-loading.
+    pub fn rewrite_images(&self, html: &str) -> Result<String, ProcessImageError> {
+        let mut output = String::with_capacity(html.len());
+        let mut rest = html;
+
+        while let Some(start) = rest.find("<img ") {
+            let Some(len) = rest[start..].find('>') else {
+                break;
+            };
+
+            output.push_str(&rest[..start]);
+            let tag = &rest[start..=start + len];
+            output.push_str(&self.rewrite_tag(tag)?);
+            rest = &rest[start + len + 1..];
+        }
+
+        output.push_str(rest);
+        Ok(output)
+    }
+
+    fn rewrite_tag(&self, tag: &str) -> Result<String, ProcessImageError> {
+        let attrs = parse_attributes(tag);
+        let attr = |name: &str| {
+            attrs
+                .iter()
+                .find(|(attr_name, _)| *attr_name == name)
+                .map(|(_, value)| PreEscaped(*value))
+        };
+
+        let Some(src) = attr("src") else {
+            return Ok(tag.to_owned());
+        };
+        let alt = attr("alt").unwrap_or(PreEscaped(""));
+        let title = attr("title");
+
+        let lazy = || {
+            html! {
+                img src=(src) alt=(alt) title=[title] loading="lazy" decoding="async";
+            }
+            .into_string()
+        };
+
+        let Some(relative) = static_relative_path(src.0)? else {
+            return Ok(lazy());
+        };
+
+        // A typo in an image path shouldn't take the whole site down, so the
+        // tag is left for the browser to show as broken instead.
+        let source_path = self.static_path.join(relative);
+        if !source_path.is_file() {
+            warn!(path = %source_path, "image does not exist, leaving its tag as it is");
+            return Ok(tag.to_owned());
+        }
+
+        let Some(processed) = self.process(src.0, relative, &source_path)? else {
+            return Ok(lazy());
+        };
+
+        if processed.webp.is_empty() {
+            return Ok(html! {
+                img src=(src)
+                    width=(processed.width)
+                    height=(processed.height)
+                    alt=(alt)
+                    title=[title]
+                    loading="lazy"
+                    decoding="async";
+            }
+            .into_string());
+        }
+
+        let srcset = |variants: &[(String, u32)]| {
+            variants
+                .iter()
+                .map(|(url, width)| format!("{url} {width}w"))
+                .collect::<Vec<_>>()
+                .join(", ")
+        };
+
+        Ok(html! {
+            picture {
+                source type="image/webp" srcset=(srcset(&processed.webp)) sizes=(SIZES);
+                img src=(src)
+                    srcset=(srcset(&processed.fallback))
+                    sizes=(SIZES)
+                    width=(processed.width)
+                    height=(processed.height)
+                    alt=(alt)
+                    title=[title]
+                    loading="lazy"
+                    decoding="async";
+            }
+        }
+        .into_string())
+    }
+
+    /// Generates the variants of the image at `src`, which is served from
+    /// `relative` in the static dir, if it's in a format that can be resized.
+    /// If there's no cache dir, only its dimensions are read.
+    fn process(
+        &self,
+        src: &str,
+        relative: &Utf8Path,
+        source_path: &Utf8Path,
+    ) -> Result<Option<ProcessedImage>, ProcessImageError> {
+        use ProcessImageError::*;
+
+        let Some(format) = ImageFormat::from_path(source_path)
+            .ok()
+            .filter(|format| format.reading_enabled() && format.writing_enabled())
+        else {
+            return Ok(None);
+        };
+
+        let (width, height) = image::image_dimensions(source_path)
+            .map_err(|err| ReadImage(source_path.to_owned(), err))?;
+
+        let Some(cache_path) = &self.cache_path else {
+            return Ok(Some(ProcessedImage {
+                width,
+                height,
+                fallback: Vec::new(),
+                webp: Vec::new(),
+            }));
+        };
+
+        let source_modified = modified(source_path)?;
+
+        // The source image is only decoded if a variant actually needs to be
+        // (re)generated.
+        let mut decoded: Option<DynamicImage> = None;
+
+        let extension = format.extensions_str()[0];
+
+        let mut fallback = Vec::new();
+        let mut webp = Vec::new();
+
+        let widths = VARIANT_WIDTHS
+            .iter()
+            .copied()
+            .filter(|&variant_width| variant_width < width)
+            .chain([width]);
+
+        for variant_width in widths {
+            let variant_height =
+                (u64::from(height) * u64::from(variant_width) / u64::from(width)).max(1) as u32;
+
+            let mut targets = vec![(ImageFormat::WebP, "webp", &mut webp)];
+            if variant_width == width {
+                fallback.push((src.to_owned(), width));
+            } else {
+                targets.push((format, extension, &mut fallback));
+            }
+
+            for (target_format, target_extension, variants) in targets {
+                // The source's extension is kept in the variant's name, so that
+                // images which only differ by extension don't overwrite each
+                // other's variants.
+                let file_name = format!("{relative}-{variant_width}.{target_extension}");
+                let target_path = cache_path.join(&file_name);
+
+                if !is_fresh(&target_path, source_modified) {
+                    let source = match decoded.take() {
+                        Some(source) => source,
+                        None => image::open(source_path)
+                            .map_err(|err| ReadImage(source_path.to_owned(), err))?,
+                    };
+                    let resized =
+                        source.resize_exact(variant_width, variant_height, FilterType::Lanczos3);
+                    decoded = Some(source);
+                    write_variant(resized, &target_path, target_format)?;
+                    info!(path = %target_path, "generated image variant");
+                }
+
+                variants.push((format!("{IMAGES_PREFIX}{file_name}"), variant_width));
+            }
+        }
+
+        Ok(Some(ProcessedImage {
+            width,
+            height,
+            fallback,
+            webp,
+        }))
+    }
+}
+
+struct ProcessedImage {
+    width: u32,
+    height: u32,
+    fallback: Vec<(String, u32)>,
+    webp: Vec<(String, u32)>,
+}
+
+#[derive(Error, Debug)]
+pub enum ProcessImageError {
+    #[error("failed to create image cache dir {0}: {1}")]
+    CreateDir(Utf8PathBuf, #[source] io::Error),
+
+    #[error("failed to access metadata of image {0}: {1}")]
+    Metadata(Utf8PathBuf, #[source] io::Error),
+
+    #[error("failed to read image {0}: {1}")]
+    ReadImage(Utf8PathBuf, #[source] ImageError),
+
+    #[error("failed to write image variant {0}: {1}")]
+    WriteVariant(Utf8PathBuf, #[source] ImageError),
+
+    #[error("image path does not point inside the static dir: {0}")]
+    OutsideStaticDir(String),
+}
+
+/// Works out where an image is in the static dir from its `src`, if it's
+/// served from there.
+fn static_relative_path(src: &str) -> Result<Option<&Utf8Path>, ProcessImageError> {
+    let Some(relative) = src.strip_prefix(STATIC_PREFIX) else {
+        return Ok(None);
+    };
+
+    let relative = Utf8Path::new(relative);
+    if relative
+        .components()
+        .any(|component| !matches!(component, Utf8Component::Normal(_)))
+    {
+        return Err(ProcessImageError::OutsideStaticDir(src.to_owned()));
+    }
+
+    Ok(Some(relative))
+}
+
+fn modified(path: &Utf8Path) -> Result<SystemTime, ProcessImageError> {
+    fs::metadata(path)
+        .and_then(|metadata| metadata.modified())
+        .map_err(|err| ProcessImageError::Metadata(path.to_owned(), err))
+}
+
+/// Whether the variant at `path` exists and was generated after the source
+/// image was last modified.
+fn is_fresh(path: &Utf8Path, source_modified: SystemTime) -> bool {
+    fs::metadata(path)
+        .and_then(|metadata| metadata.modified())
+        .is_ok_and(|variant_modified| variant_modified >= source_modified)
+}
+
+fn write_variant(
+    image: DynamicImage,
+    path: &Utf8Path,
+    format: ImageFormat,
+) -> Result<(), ProcessImageError> {
+    use ProcessImageError::*;
+
+    if let Some(parent) = path.parent() {
+        fs::create_dir_all(parent).map_err(|err| CreateDir(parent.to_owned(), err))?;
+    }
+
+    // Not every encoder supports every pixel format (JPEG has no alpha, the
+    // WebP encoder only takes 8-bit channels), so convert to one that works.
+    let image = match format {
+        ImageFormat::Jpeg => DynamicImage::ImageRgb8(image.to_rgb8()),
+        _ if image.color().has_alpha() => DynamicImage::ImageRgba8(image.to_rgba8()),
+        _ => DynamicImage::ImageRgb8(image.to_rgb8()),
+    };
+
+    image
+        .save_with_format(path, format)
+        .map_err(|err| WriteVariant(path.to_owned(), err))
+}
+
+/// Parses the attributes of a single HTML tag, as written by comrak, into
+/// pairs of names and (still escaped) values.
+fn parse_attributes(tag: &str) -> Vec<(&str, &str)> {
+    let mut attrs = Vec::new();
+    let mut rest = tag
+        .trim_start_matches('<')
+        .trim_end_matches('>')
+        .trim_end_matches('/');
+
+    // Skip the tag name.
+    rest = rest
+        .trim_start()
+        .split_once(' ')
+        .map_or("", |(_, rest)| rest);
+
+    loop {
+        rest = rest.trim_start();
+        if rest.is_empty() {
+            break;
+        }
+
+        let name_end = rest
+            .find(|c: char| c == '=' || c.is_whitespace())
+            .unwrap_or(rest.len());
+        let name = &rest[..name_end];
+        rest = &rest[name_end..];
+
+        if let Some(after_eq) = rest.strip_prefix('=') {
+            if let Some(quoted) = after_eq.strip_prefix('"') {
+                let value_end = quoted.find('"').unwrap_or(quoted.len());
+                attrs.push((name, &quoted[..value_end]));
+                rest = quoted.get(value_end + 1..).unwrap_or("");
+            } else {
+                let value_end = after_eq.find(char::is_whitespace).unwrap_or(after_eq.len());
+                attrs.push((name, &after_eq[..value_end]));
+                rest = &after_eq[value_end..];
+            }
+        } else {
+            attrs.push((name, ""));
+        }
+    }
+
+    attrs
+}
@@ -0,0 +1,25 @@
+use base64::{engine::general_purpose::STANDARD, Engine};
+use sha2::{Digest, Sha256, Sha384};
+
+/// Builds a Subresource Integrity value for some content, for use in an
+/// `integrity` attribute.
+pub fn sri_hash(content: &[u8]) -> String {
+    format!("sha384-{}", STANDARD.encode(Sha384::digest(content)))
+}
+
+/// Builds a Content Security Policy source that allows an inline element with
+/// exactly this content, e.g. a `<style>` element.
+pub fn csp_hash_source(content: &str) -> String {
+    format!("'sha256-{}'", STANDARD.encode(Sha256::digest(content)))
+}
+
+/// Finds the content of every inline `<style>` element in some HTML, such as
+/// the ones that svgbob puts inside each diagram.
+pub fn inline_styles(html: &str) -> impl Iterator<Item = &str> {
+    html.match_indices("<style").filter_map(move |(start, _)| {
+        let after_tag = &html[start..];
+        let content_start = after_tag.find('>')? + 1;
+        let content_len = after_tag[content_start..].find("</style>")?;
+        Some(&after_tag[content_start..content_start + content_len])
+    })
+}
@@ -0,0 +1,254 @@
+use latex2mathml::{latex_to_mathml, DisplayStyle, LatexError};
+use thiserror::Error;
+use uuid::Uuid;
+
+/// Math that has been pulled out of some markdown and rendered to MathML, to
+/// be put back once the markdown has been rendered to HTML.
+///
+/// LaTeX is full of characters that mean something in markdown (`_`, `*`,
+/// `\`), so rather than render it in place, each expression is swapped for an
+/// alphanumeric placeholder that comrak will pass through untouched.
+pub struct ExtractedMath {
+    placeholder_prefix: String,
+    rendered: Vec<(DisplayStyle, String)>,
+}
+
+impl ExtractedMath {
+    /// Finds all the `$inline$` and `$$display$$` math in some markdown,
+    /// returning the markdown with placeholders in place of the math.
+    ///
+    /// Dollar signs inside code spans and fenced or indented code blocks, or
+    /// escaped with a backslash, are left alone. Following Pandoc, an opening
+    /// `$` must be followed by a non-space character, and a closing `$` must be
+    /// preceded by a non-space character and not followed by a digit, so
+    /// prices like "$5 or $10" aren't mistaken for math.
+    pub fn extract(markdown: &str) -> Result<(String, Self), RenderMathError> {
+        let mut extracted = Self {
+            placeholder_prefix: format!("math{}n", Uuid::new_v4().simple()),
+            rendered: Vec::new(),
+        };
+        let mut output = String::with_capacity(markdown.len());
+        let mut fence: Option<(char, usize)> = None;
+        let mut indented_code = false;
+        let mut in_list = false;
+        let mut paragraph = String::new();
+
+        for line in markdown.split_inclusive('\n') {
+            let trimmed = line.trim_start();
+            let indent = indentation(line);
+            let is_blank = trimmed.trim_end().is_empty();
+
+            // Text indented under a list item belongs to the item rather than
+            // being code, so lists need to be kept track of.
+            if fence.is_none() && !is_blank && indent < 4 {
+                if is_list_marker(trimmed) {
+                    in_list = true;
+                } else if indent == 0 && paragraph.is_empty() {
+                    in_list = false;
+                }
+            }
+
+            if let Some((fence_char, fence_len)) = fence {
+                output.push_str(line);
+                let trimmed = trimmed.trim_end();
+                if trimmed.len() >= fence_len && trimmed.chars().all(|c| c == fence_char) {
+                    fence = None;
+                }
+            } else if indented_code && (is_blank || indent >= 4) {
+                output.push_str(line);
+            } else if !is_blank && indent >= 4 && paragraph.is_empty() && !in_list {
+                // An indented code block can't interrupt a paragraph, so it
+                // only starts after a blank line or another block.
+                indented_code = true;
+                output.push_str(line);
+            } else if let Some(open_fence) = fence_marker(trimmed) {
+                indented_code = false;
+                output.push_str(&extracted.replace_math(&paragraph)?);
+                paragraph.clear();
+                output.push_str(line);
+                fence = Some(open_fence);
+            } else {
+                indented_code = false;
+                paragraph.push_str(line);
+                if trimmed.is_empty() {
+                    output.push_str(&extracted.replace_math(&paragraph)?);
+                    paragraph.clear();
+                }
+            }
+        }
+        output.push_str(&extracted.replace_math(&paragraph)?);
+
+        Ok((output, extracted))
+    }
+
+    /// Puts the rendered math back into the HTML rendered from the markdown
+    /// returned by [`extract()`](Self::extract).
+    pub fn restore(&self, html: &str) -> String {
+        let mut html = html.to_owned();
+
+        for (index, (display, mathml)) in self.rendered.iter().enumerate() {
+            let placeholder = self.placeholder(index);
+
+            // Display math on its own ends up in a paragraph, which it should
+            // replace rather than sit inside.
+            if *display == DisplayStyle::Block {
+                html = html.replace(&format!("<p>{placeholder}</p>"), mathml);
+            }
+            html = html.replace(&placeholder, mathml);
+        }
+
+        html
+    }
+
+    fn placeholder(&self, index: usize) -> String {
+        format!("{}{index}end", self.placeholder_prefix)
+    }
+
+    fn render(&mut self, latex: &str, display: DisplayStyle) -> Result<String, RenderMathError> {
+        let mathml = latex_to_mathml(latex, display).map_err(|source| RenderMathError {
+            latex: latex.to_owned(),
+            source,
+        })?;
+        self.rendered.push((display, mathml));
+        Ok(self.placeholder(self.rendered.len() - 1))
+    }
+
+    /// Replaces the math in a run of lines outside any code fence.
+    fn replace_math(&mut self, text: &str) -> Result<String, RenderMathError> {
+        let mut output = String::with_capacity(text.len());
+        let mut rest = text;
+
+        while let Some(index) = rest.find(['$', '`', '\\']) {
+            output.push_str(&rest[..index]);
+            rest = &rest[index..];
+
+            if rest.starts_with('\\') {
+                // Escaped characters (including `\$`) are left for comrak.
+                let len = rest[1..].chars().next().map_or(1, |c| 1 + c.len_utf8());
+                output.push_str(&rest[..len]);
+                rest = &rest[len..];
+            } else if rest.starts_with('`') {
+                // Code spans are copied verbatim, up to the matching run of
+                // backticks.
+                let ticks = rest.len() - rest.trim_start_matches('`').len();
+                let after_open = &rest[ticks..];
+                let len = after_open
+                    .find(&rest[..ticks])
+                    .map_or(ticks, |close| ticks + close + ticks);
+                output.push_str(&rest[..len]);
+                rest = &rest[len..];
+            } else if let Some(after_open) = rest.strip_prefix("$$") {
+                match after_open.find("$$") {
+                    Some(close) => {
+                        output.push_str(&self.render(&after_open[..close], DisplayStyle::Block)?);
+                        rest = &after_open[close + 2..];
+                    }
+                    None => {
+                        output.push_str("$$");
+                        rest = after_open;
+                    }
+                }
+            } else {
+                let after_open = &rest[1..];
+                match inline_math_len(after_open) {
+                    Some(len) => {
+                        output.push_str(&self.render(&after_open[..len], DisplayStyle::Inline)?);
+                        rest = &after_open[len + 1..];
+                    }
+                    None => {
+                        output.push('$');
+                        rest = after_open;
+                    }
+                }
+            }
+        }
+
+        output.push_str(rest);
+        Ok(output)
+    }
+}
+
+#[derive(Error, Debug)]
+#[error("failed to render math \"{latex}\": {source}")]
+pub struct RenderMathError {
+    latex: String,
+    #[source]
+    source: LatexError,
+}
+
+/// If the line opens a fenced code block, returns the character and minimum
+/// length of the fence that will close it.
+fn fence_marker(line: &str) -> Option<(char, usize)> {
+    ['`', '~'].into_iter().find_map(|fence_char| {
+        let len = line.len() - line.trim_start_matches(fence_char).len();
+        (len >= 3).then_some((fence_char, len))
+    })
+}
+
+/// How many columns a line is indented by, with tabs counting up to the next
+/// multiple of four like they do in markdown.
+fn indentation(line: &str) -> usize {
+    let mut columns = 0;
+    for c in line.chars() {
+        match c {
+            ' ' => columns += 1,
+            '\t' => columns += 4 - columns % 4,
+            _ => break,
+        }
+    }
+    columns
+}
+
+/// Whether a line (with its indentation trimmed) starts a list item.
+fn is_list_marker(line: &str) -> bool {
+    let after_marker = match line.strip_prefix(['-', '*', '+']) {
+        Some(rest) => rest,
+        None => {
+            let digits = line.len() - line.trim_start_matches(|c: char| c.is_ascii_digit()).len();
+            if digits == 0 {
+                return false;
+            }
+            match line[digits..].strip_prefix(['.', ')']) {
+                Some(rest) => rest,
+                None => return false,
+            }
+        }
+    };
+
+    after_marker.is_empty() || after_marker.starts_with(char::is_whitespace)
+}
+
+/// Finds the length of the inline math that starts just after an opening `$`,
+/// if there is any.
+fn inline_math_len(text: &str) -> Option<usize> {
+    if text.starts_with(char::is_whitespace) {
+        return None;
+    }
+
+    let mut search_from = 0;
+    while let Some(close) = text[search_from..]
+        .find(['$', '`'])
+        .map(|index| search_from + index)
+    {
+        // Math can't contain code spans, so hitting one means this `$` wasn't
+        // the start of any math.
+        if text[close..].starts_with('`') {
+            return None;
+        }
+
+        if text[..close].ends_with('\\') {
+            search_from = close + 1;
+            continue;
+        }
+
+        let before = text[..close].chars().next_back();
+        let after = text[close + 1..].chars().next();
+
+        return (close > 0
+            && before.is_some_and(|c| !c.is_whitespace())
+            && !after.is_some_and(|c| c.is_ascii_digit()))
+        .then_some(close);
+    }
+
+    None
+}
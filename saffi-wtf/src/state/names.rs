@@ -33,6 +33,31 @@ impl TryFrom<String> for GroupName {
     }
 }
 
+impl GroupName {
+    /// The route the group's page is served at.
+    pub fn route(&self) -> String {
+        format!("/{self}")
+    }
+
+    /// The route a post in the group is served at. Posts in the root group
+    /// are served at the top level, rather than under an empty segment.
+    pub fn post_route(&self, post_name: &PageName) -> String {
+        match self {
+            GroupName::Root => format!("/{post_name}"),
+            GroupName::Named(name) => format!("/{name}/{post_name}"),
+        }
+    }
+}
+
+impl fmt::Display for GroupName {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            GroupName::Root => Ok(()),
+            GroupName::Named(name) => f.write_str(name),
+        }
+    }
+}
+
 #[derive(Error, Debug)]
 pub enum ParseGroupNameError {
     #[error("group name \"{0}\" contains invalid char '{1}'")]
@@ -42,6 +67,12 @@ pub enum ParseGroupNameError {
 #[derive(Clone, Debug, Hash, PartialEq, Eq)]
 pub struct TagName(String);
 
+impl fmt::Display for TagName {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(&self.0)
+    }
+}
+
 impl TryFrom<String> for TagName {
     type Error = ParseTagNameError;
 
@@ -107,6 +138,7 @@ impl<'de> Deserialize<'de> for TagName {
 #[derive(Clone, Debug, Hash, PartialEq, Eq)]
 pub enum PageName {
     Index(Uuid),
+    ErrorPage(Uuid),
     Named(String),
 }
 
@@ -114,6 +146,19 @@ impl PageName {
     pub fn new_index() -> Self {
         Self::Index(Uuid::new_v4())
     }
+
+    pub fn new_error_page() -> Self {
+        Self::ErrorPage(Uuid::new_v4())
+    }
+}
+
+impl fmt::Display for PageName {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            PageName::Index(_) | PageName::ErrorPage(_) => Ok(()),
+            PageName::Named(name) => f.write_str(name),
+        }
+    }
 }
 
 impl TryFrom<String> for PageName {
@@ -0,0 +1,150 @@
+use std::collections::{hash_map::Entry, HashMap, HashSet};
+
+use axum::http::{uri::InvalidUri, Uri};
+use serde::Deserialize;
+use thiserror::Error;
+
+/// The name of the file in the root of the content dir that lists site-level
+/// redirects, as a TOML table mapping old paths to new ones.
+pub const REDIRECTS_FILE_NAME: &str = "_redirects.toml";
+
+/// The name of the file in the root of the content dir that lists paths that
+/// have been deliberately removed, and should be reported as gone rather than
+/// not found.
+pub const GONE_FILE_NAME: &str = "_gone.toml";
+
+/// Paths that are served by something other than content, and so can't be
+/// redirected away from or used by content.
+const RESERVED_PATHS: &[&str] = &["/style.css", "/healthz", "/readyz", "/version", "/metrics"];
+
+/// Path prefixes that are served by something other than content, and so
+/// can't be redirected away from or used by content.
+const RESERVED_PREFIXES: &[&str] = &["/static/", "/images/", "/_admin/"];
+
+/// The contents of the gone file.
+#[derive(Deserialize, Clone, Debug, Default)]
+pub struct GoneFile {
+    pub paths: Vec<String>,
+}
+
+/// A table of paths that redirect to other paths, built from the site-level
+/// redirects file and the `aliases` in page frontmatter, along with the paths
+/// listed in the gone file.
+#[derive(Clone, Debug, Default)]
+pub struct Redirects {
+    targets: HashMap<String, Uri>,
+    gone: HashSet<String>,
+}
+
+impl Redirects {
+    /// Adds a redirect from one path to another.
+    ///
+    /// Fails if the path already redirects somewhere else, so that two pages
+    /// can't quietly claim the same old URL, or if the target isn't a valid
+    /// URL, so that it's found out now rather than when it's requested.
+    pub fn insert(&mut self, from: &str, to: &str) -> Result<(), RedirectError> {
+        let to = to
+            .parse::<Uri>()
+            .map_err(|err| RedirectError::InvalidTarget(from.to_owned(), to.to_owned(), err))?;
+
+        match self.targets.entry(normalise_path(from)) {
+            Entry::Occupied(entry) => Err(RedirectError::Duplicate(entry.key().clone())),
+            Entry::Vacant(entry) => {
+                entry.insert(to);
+                Ok(())
+            }
+        }
+    }
+
+    /// Marks a path as gone, so that requests for it get told it's been
+    /// removed.
+    pub fn insert_gone(&mut self, path: &str) {
+        self.gone.insert(normalise_path(path));
+    }
+
+    /// Checks that no redirect or gone path would shadow one of the given
+    /// routes, or any path served by something other than content, that none
+    /// of the routes are themselves shadowed by something other than content,
+    /// and that nothing is both gone and redirected.
+    pub fn check_collisions(&self, routes: &HashSet<String>) -> Result<(), RedirectError> {
+        if let Some(route) = routes.iter().find(|route| is_reserved(route)) {
+            return Err(RedirectError::ReservedRoute(route.clone()));
+        }
+
+        if let Some(path) = self
+            .gone
+            .iter()
+            .find(|path| self.targets.contains_key(*path))
+        {
+            return Err(RedirectError::GoneAndRedirected(path.clone()));
+        }
+
+        for from in self.targets.keys().chain(&self.gone) {
+            if routes.contains(from) || is_reserved(from) {
+                return Err(RedirectError::ShadowsRoute(from.clone()));
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Looks up where a request path should be redirected to, if anywhere.
+    pub fn target(&self, path: &str) -> Option<&Uri> {
+        self.targets.get(&normalise_path(path))
+    }
+
+    /// Checks whether a request path has been marked as gone.
+    pub fn is_gone(&self, path: &str) -> bool {
+        self.gone.contains(&normalise_path(path))
+    }
+}
+
+#[derive(Error, Debug)]
+pub enum RedirectError {
+    #[error("more than one redirect is declared from path {0}")]
+    Duplicate(String),
+
+    #[error("redirect from path {0} has an invalid target {1}: {2}")]
+    InvalidTarget(String, String, #[source] InvalidUri),
+
+    #[error("redirected or gone path {0} would shadow a real route")]
+    ShadowsRoute(String),
+
+    #[error("content route {0} is already used by something other than content")]
+    ReservedRoute(String),
+
+    #[error("path {0} is both gone and redirected")]
+    GoneAndRedirected(String),
+}
+
+/// Checks whether a normalised path is served by something other than
+/// content.
+fn is_reserved(path: &str) -> bool {
+    RESERVED_PATHS.contains(&path)
+        || RESERVED_PREFIXES
+            .iter()
+            .any(|prefix| path.starts_with(prefix) || path == prefix.trim_end_matches('/'))
+}
+
+/// Normalises a path so that it can be compared against routes, by making sure
+/// it starts with a slash and doesn't end with one.
+pub fn normalise_path(path: &str) -> String {
+    let trimmed = path.trim_matches('/');
+    format!("/{trimmed}")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn rejects_invalid_targets() {
+        let mut redirects = Redirects::default();
+        let error = redirects.insert("/old", "/new\nLocation").unwrap_err();
+        assert!(matches!(
+            error,
+            RedirectError::InvalidTarget(from, to, _) if from == "/old" && to == "/new\nLocation"
+        ));
+        assert_eq!(redirects.target("/old"), None);
+    }
+}
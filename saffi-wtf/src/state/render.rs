@@ -1,20 +1,71 @@
+use std::fmt::Write as _;
+
 use maud::{html, Markup, PreEscaped, Render};
 
-use crate::state::{names::GroupName, Content, Group, Post, PostName, Tag};
+use crate::state::{names::GroupName, text, Content, Group, Page, Post, PostName, Tag};
 
 pub struct GroupRef<'a> {
     pub group: &'a Group,
+    pub name: GroupName,
     pub content: &'a Content,
 }
 
-impl<'a> Render for GroupRef<'a> {
-    fn render(&self) -> Markup {
-        let mb_index_content = self
-            .group
+impl<'a> GroupRef<'a> {
+    /// The group's index page, if it has one.
+    pub fn index_page(&self) -> Option<&'a Page> {
+        self.group
             .index
             .as_ref()
             .and_then(|page_name| self.content.pages.get(page_name))
-            .map(|page| page.html_content.as_str());
+    }
+
+    /// The posts in the group, newest first.
+    fn posts(&self) -> Vec<PostSummaryRef<'_>> {
+        let mut posts = self
+            .group
+            .members
+            .iter()
+            .filter_map(|name| {
+                self.content.posts.get(name).map(|post| PostSummaryRef {
+                    post,
+                    group_name: &self.name,
+                    name,
+                })
+            })
+            .collect::<Vec<_>>();
+        posts.sort_by(|a, b| b.post.date.cmp(&a.post.date));
+        posts
+    }
+
+    /// The group rendered as plain text: its index page, if it has one,
+    /// followed by a list of its posts.
+    pub fn text(&self) -> String {
+        let mut text = self.index_page().map(Page::text).unwrap_or_default();
+
+        let posts = self.posts();
+        if !posts.is_empty() && !text.is_empty() {
+            text.push('\n');
+        }
+        for post in posts {
+            let title = post.post.title.as_deref().map(text::strip_tags);
+            let _ = writeln!(
+                text,
+                "- {}: {} <{}>",
+                post.post.date,
+                title.as_deref().unwrap_or(&post.name.to_string()),
+                post.group_name.post_route(post.name),
+            );
+        }
+
+        text
+    }
+}
+
+impl<'a> Render for GroupRef<'a> {
+    fn render(&self) -> Markup {
+        let mb_index_content = self.index_page().map(|page| page.html_content.as_str());
+
+        let posts = self.posts();
 
         html! {
             main class="page" {
@@ -23,6 +74,14 @@ impl<'a> Render for GroupRef<'a> {
                 } else {
                     PreEscaped("")
                 })
+
+                @if !posts.is_empty() {
+                    section class="posts" {
+                        @for post in &posts {
+                            (post)
+                        }
+                    }
+                }
             }
         }
     }
@@ -45,9 +104,46 @@ impl<'a> Render for PostRef<'a> {
             article {
                 ul class="frontmatter" {
                     li { (self.post.date) }
+                    li { (self.post.word_count) " words, " (self.post.reading_time()) " min read" }
                 }
                 (PreEscaped(&self.post.html_content))
             }
         }
     }
 }
+
+/// A post as it appears in a listing: its title, date, reading time and
+/// summary, linking to the full post.
+pub struct PostSummaryRef<'a> {
+    pub post: &'a Post,
+    pub group_name: &'a GroupName,
+    pub name: &'a PostName,
+}
+
+impl<'a> Render for PostSummaryRef<'a> {
+    fn render(&self) -> Markup {
+        let href = self.group_name.post_route(self.name);
+
+        html! {
+            article class="summary" {
+                h2 {
+                    // Titles can contain links of their own, which can't be
+                    // nested inside this one, so only their text is kept.
+                    a href=(href) {
+                        @if let Some(title) = &self.post.title {
+                            (text::strip_tags(title))
+                        } @else {
+                            (self.name)
+                        }
+                    }
+                }
+                ul class="frontmatter" {
+                    li { (self.post.date) }
+                    li { (self.post.reading_time()) " min read" }
+                }
+                (PreEscaped(&self.post.summary_html))
+                a class="more" href=(href) { "read more" }
+            }
+        }
+    }
+}
@@ -0,0 +1,91 @@
+use std::{cmp::Ordering, collections::HashSet};
+
+use crate::state::redirects::normalise_path;
+
+/// How many routes are suggested at most.
+pub const MAX_SUGGESTIONS: usize = 3;
+
+/// The longest path that routes are suggested for. Comparing paths takes time
+/// in proportion to the product of their lengths, and nothing this long is a
+/// typo of a real route anyway.
+const MAX_PATH_LEN: usize = 128;
+
+/// How close a route has to be to the requested path by edits, from 0 to 1,
+/// to be suggested as what a typo was meant to be.
+const MIN_EDIT_SCORE: f64 = 0.75;
+
+/// The fraction of the requested path's words a route has to contain to be
+/// suggested as where an old URL moved to. Sharing only half isn't enough, so
+/// that a path in a group doesn't get every other route in the group
+/// suggested.
+const MIN_WORD_SCORE: f64 = 0.5;
+
+/// Finds the routes closest to a path that doesn't exist, best first, for
+/// suggesting where the reader might have meant to go.
+///
+/// Routes are suggested if it'd only take a few edits to turn the path into
+/// them (which catches typos), or if they contain most of the path's words
+/// (which catches old URLs for things that have moved).
+pub fn closest<'a>(path: &str, routes: &'a [String]) -> Vec<&'a str> {
+    if path.len() > MAX_PATH_LEN {
+        return Vec::new();
+    }
+
+    let path = normalise_path(path).to_lowercase();
+    let path_words = words(&path);
+
+    let mut scored = routes
+        .iter()
+        .filter(|route| **route != path)
+        .filter_map(|route| {
+            // Parents they have in common would make any two paths look
+            // similar, so only what's different is compared.
+            let (path_rest, route_rest) = without_common_parents(&path, route);
+            let edit_score = strsim::normalized_levenshtein(path_rest, route_rest);
+
+            let word_score = if path_words.is_empty() {
+                0.0
+            } else {
+                path_words.intersection(&words(route)).count() as f64 / path_words.len() as f64
+            };
+
+            (edit_score >= MIN_EDIT_SCORE || word_score > MIN_WORD_SCORE)
+                .then(|| (edit_score.max(word_score), route.as_str()))
+        })
+        .collect::<Vec<_>>();
+
+    scored.sort_by(|(a_score, a_route), (b_score, b_route)| {
+        b_score
+            .partial_cmp(a_score)
+            .unwrap_or(Ordering::Equal)
+            .then_with(|| a_route.cmp(b_route))
+    });
+
+    scored
+        .into_iter()
+        .take(MAX_SUGGESTIONS)
+        .map(|(_, route)| route)
+        .collect()
+}
+
+/// Strips the leading segments two paths have in common.
+fn without_common_parents<'a, 'b>(a: &'a str, b: &'b str) -> (&'a str, &'b str) {
+    let mut common = 0;
+    for (i, (a_byte, b_byte)) in a.bytes().zip(b.bytes()).enumerate() {
+        if a_byte != b_byte {
+            break;
+        }
+        if a_byte == b'/' {
+            common = i + 1;
+        }
+    }
+
+    (&a[common..], &b[common..])
+}
+
+/// Splits a path into the words in its segments.
+fn words(path: &str) -> HashSet<&str> {
+    path.split(['/', '-', '_', '.'])
+        .filter(|word| !word.is_empty())
+        .collect()
+}
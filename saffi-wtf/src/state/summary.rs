@@ -0,0 +1,100 @@
+use comrak::{nodes::NodeValue, parse_document, Arena, ComrakOptions};
+
+/// The marker that separates the summary of a post from the rest of it.
+const MORE_MARKER: &str = "<!-- more -->";
+
+/// The average adult reading speed, used to estimate reading times.
+const WORDS_PER_MINUTE: usize = 200;
+
+/// Splits the markdown of a post at the `<!-- more -->` marker, if it contains
+/// one, returning the markdown before the marker and the full markdown with the
+/// marker removed.
+///
+/// The marker has to be on a line of its own, outside of any other block, so
+/// that a post can show it in code without being cut short there.
+pub fn split_at_marker<'a>(
+    markdown: &'a str,
+    options: &ComrakOptions,
+) -> Option<(&'a str, String)> {
+    let arena = Arena::new();
+    let root = parse_document(&arena, markdown, options);
+    let marker_line = root.children().find_map(|node| {
+        let data = node.data.borrow();
+        match &data.value {
+            NodeValue::HtmlBlock(block) if block.literal.trim() == MORE_MARKER => {
+                Some(data.sourcepos.start.line)
+            }
+            _ => None,
+        }
+    })?;
+
+    let mut lines = markdown.split_inclusive('\n');
+    let start = lines
+        .by_ref()
+        .take(marker_line - 1)
+        .map(str::len)
+        .sum::<usize>();
+    let end = start + lines.next().map_or(0, str::len);
+    let before = &markdown[..start];
+    Some((before, format!("{before}{}", &markdown[end..])))
+}
+
+/// Finds the contents of the first `<h1>` in some rendered HTML.
+pub fn title(html: &str) -> Option<&str> {
+    let (_, rest) = html.split_once("<h1>")?;
+    let (title, _) = rest.split_once("</h1>")?;
+    Some(title)
+}
+
+/// Removes the first `<h1>` from some rendered HTML, so that a summary doesn't
+/// repeat the title of the post it summarises.
+pub fn strip_title(html: &str) -> String {
+    let Some((before, rest)) = html.split_once("<h1>") else {
+        return html.to_owned();
+    };
+    let Some((_, after)) = rest.split_once("</h1>") else {
+        return html.to_owned();
+    };
+    format!("{before}{}", after.trim_start())
+}
+
+/// Finds the first paragraph in some rendered HTML, including its tags.
+pub fn first_paragraph(html: &str) -> Option<&str> {
+    let start = html.find("<p>")?;
+    let len = html[start..].find("</p>")? + "</p>".len();
+    Some(&html[start..start + len])
+}
+
+/// Counts the words in some markdown, ignoring any tokens (like list bullets
+/// and heading markers) that don't contain any alphanumeric characters.
+pub fn word_count(markdown: &str) -> usize {
+    markdown
+        .split_whitespace()
+        .filter(|word| word.chars().any(char::is_alphanumeric))
+        .count()
+}
+
+/// Estimates how long it will take to read the given number of words, rounded
+/// up to the nearest minute.
+pub fn reading_time(word_count: usize) -> usize {
+    word_count.div_ceil(WORDS_PER_MINUTE).max(1)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn splits_at_the_marker() {
+        let markdown = "First bit.\n\n<!-- more -->\n\nSecond bit.\n";
+        let (before, full) = split_at_marker(markdown, &ComrakOptions::default()).unwrap();
+        assert_eq!(before, "First bit.\n\n");
+        assert_eq!(full, "First bit.\n\n\nSecond bit.\n");
+    }
+
+    #[test]
+    fn ignores_markers_in_code() {
+        let markdown = "Use `<!-- more -->` like this:\n\n```html\n<!-- more -->\n```\n";
+        assert_eq!(split_at_marker(markdown, &ComrakOptions::default()), None);
+    }
+}
@@ -0,0 +1,102 @@
+use std::fmt::Write as _;
+
+use comrak::{
+    nodes::{AstNode, NodeValue},
+    parse_document, Arena, ComrakOptions,
+};
+
+/// Renders some markdown as plain text, for clients that don't want HTML.
+///
+/// Formatting is dropped, links are followed by their URL in angle brackets,
+/// and raw HTML (like the `<!-- more -->` marker) is left out entirely. It's
+/// parsed with the same `options` as the HTML, so the same extensions apply.
+pub fn markdown_to_text(markdown: &str, options: &ComrakOptions) -> String {
+    let arena = Arena::new();
+    let root = parse_document(&arena, markdown, options);
+
+    let mut text = String::new();
+    write_node(root, &mut text);
+
+    let mut text = text.trim().to_owned();
+    text.push('\n');
+    text
+}
+
+/// Strips the tags out of a snippet of HTML, like a title, and decodes the
+/// entities comrak escapes text with.
+pub fn strip_tags(html: &str) -> String {
+    let mut text = String::with_capacity(html.len());
+    let mut in_tag = false;
+    for c in html.chars() {
+        match c {
+            '<' => in_tag = true,
+            '>' => in_tag = false,
+            c if !in_tag => text.push(c),
+            _ => {}
+        }
+    }
+
+    text.replace("&lt;", "<")
+        .replace("&gt;", ">")
+        .replace("&quot;", "\"")
+        .replace("&#x27;", "'")
+        .replace("&amp;", "&")
+}
+
+fn write_node<'a>(node: &'a AstNode<'a>, text: &mut String) {
+    let value = node.data.borrow().value.clone();
+
+    match &value {
+        NodeValue::Text(literal) => text.push_str(literal),
+        NodeValue::Code(code) => text.push_str(&code.literal),
+        NodeValue::SoftBreak => text.push(' '),
+        NodeValue::LineBreak => text.push('\n'),
+        NodeValue::Item(_) => text.push_str("- "),
+        NodeValue::TableCell if node.previous_sibling().is_some() => text.push_str(" | "),
+        NodeValue::FootnoteReference(footnote) => {
+            let _ = write!(text, "[^{}]", footnote.name);
+        }
+        NodeValue::FootnoteDefinition(footnote) => {
+            let _ = write!(text, "[^{}]: ", footnote.name);
+        }
+        NodeValue::CodeBlock(code_block) => {
+            text.push_str(&code_block.literal);
+            text.push('\n');
+            return;
+        }
+        NodeValue::ThematicBreak => {
+            text.push_str("---\n\n");
+            return;
+        }
+        NodeValue::HtmlBlock(_) | NodeValue::HtmlInline(_) => return,
+        _ => {}
+    }
+
+    for child in node.children() {
+        write_node(child, text);
+    }
+
+    match &value {
+        NodeValue::Link(link) => {
+            text.push_str(" <");
+            text.push_str(&link.url);
+            text.push('>');
+        }
+        NodeValue::Paragraph | NodeValue::Heading(_) | NodeValue::Item(_) => {
+            if !text.ends_with('\n') {
+                text.push('\n');
+            }
+            // Items in a list are kept together, but there's a blank line
+            // between everything else.
+            let in_item = node
+                .parent()
+                .is_some_and(|parent| matches!(parent.data.borrow().value, NodeValue::Item(_)));
+            if !matches!(value, NodeValue::Item(_)) && !in_item {
+                text.push('\n');
+            }
+        }
+        NodeValue::List(_) | NodeValue::Table(_) => text.push('\n'),
+        NodeValue::TableRow(_) => text.push('\n'),
+        _ => {}
+    }
+}
@@ -1,16 +1,20 @@
-use maud::{html, Markup};
+use maud::{html, Markup, PreEscaped};
+use tracing::instrument;
 
 use crate::{
+    errors::ErrorId,
     state::{
         render::{GroupRef, PostRef, TagRef},
-        Theme,
+        Page, Theme,
     },
     templates::wrappers,
 };
 
-pub async fn post(page: PostRef<'_>, theme: Theme) -> Markup {
+#[instrument(skip_all, fields(%canonical_url))]
+pub async fn post(page: PostRef<'_>, theme: Theme, canonical_url: String) -> Markup {
     wrappers::base(
         theme,
+        Some(canonical_url),
         html! {
             (page)
         },
@@ -18,9 +22,11 @@ pub async fn post(page: PostRef<'_>, theme: Theme) -> Markup {
     .await
 }
 
-pub async fn group(group: GroupRef<'_>, theme: Theme) -> Markup {
+#[instrument(skip_all, fields(%canonical_url))]
+pub async fn group(group: GroupRef<'_>, theme: Theme, canonical_url: String) -> Markup {
     wrappers::base(
         theme,
+        Some(canonical_url),
         html! {
             (group)
         },
@@ -28,9 +34,11 @@ pub async fn group(group: GroupRef<'_>, theme: Theme) -> Markup {
     .await
 }
 
-pub async fn tagged(_tag: TagRef<'_>, theme: Theme) -> Markup {
+#[instrument(skip_all, fields(%canonical_url))]
+pub async fn tagged(_tag: TagRef<'_>, theme: Theme, canonical_url: String) -> Markup {
     wrappers::base(
         theme,
+        Some(canonical_url),
         html! {
             ("nothing here yet")
         },
@@ -38,17 +46,19 @@ pub async fn tagged(_tag: TagRef<'_>, theme: Theme) -> Markup {
     .await
 }
 
-pub async fn not_found(theme: Theme) -> Markup {
+#[instrument(skip_all)]
+pub async fn bad_request(theme: Theme) -> Markup {
     wrappers::base(
         theme,
+        None,
         html! {
             main class="error" {
                 h1 {
-                    "not found"
+                    "bad request"
                 }
 
                 p {
-                    "wtf did you do?! that's not a route you can access."
+                    "wtf is that supposed to mean? that's not a request i can make sense of."
                 }
             }
         },
@@ -56,17 +66,132 @@ pub async fn not_found(theme: Theme) -> Markup {
     .await
 }
 
-pub async fn internal_error(theme: Theme) -> Markup {
+#[instrument(skip_all)]
+pub async fn unauthorized(theme: Theme) -> Markup {
     wrappers::base(
         theme,
+        None,
         html! {
             main class="error" {
                 h1 {
-                    "internal server error"
+                    "unauthorised"
                 }
 
                 p {
-                    "wtf, you broke it?! stop doing that."
+                    "wtf, who are you? you're not allowed in here."
+                }
+            }
+        },
+    )
+    .await
+}
+
+/// The not found page, using the one authored in content if there is one.
+/// Either way, any suggestions for where the reader meant to go are listed at
+/// the end.
+#[instrument(skip_all)]
+pub async fn not_found(theme: Theme, authored: Option<&Page>, suggestions: &[&str]) -> Markup {
+    wrappers::base(
+        theme,
+        None,
+        html! {
+            main class="error" {
+                @if let Some(page) = authored {
+                    (PreEscaped(&page.html_content))
+                } @else {
+                    h1 {
+                        "not found"
+                    }
+
+                    p {
+                        "wtf did you do?! that's not a route you can access."
+                    }
+                }
+
+                @if !suggestions.is_empty() {
+                    p {
+                        "did you mean:"
+                    }
+
+                    ul class="suggestions" {
+                        @for suggestion in suggestions {
+                            li {
+                                a href=(suggestion) { (suggestion) }
+                            }
+                        }
+                    }
+                }
+            }
+        },
+    )
+    .await
+}
+
+#[instrument(skip_all)]
+pub async fn method_not_allowed(theme: Theme) -> Markup {
+    wrappers::base(
+        theme,
+        None,
+        html! {
+            main class="error" {
+                h1 {
+                    "method not allowed"
+                }
+
+                p {
+                    "wtf are you trying to do? you can't do that here."
+                }
+            }
+        },
+    )
+    .await
+}
+
+#[instrument(skip_all)]
+pub async fn gone(theme: Theme) -> Markup {
+    wrappers::base(
+        theme,
+        None,
+        html! {
+            main class="error" {
+                h1 {
+                    "gone"
+                }
+
+                p {
+                    "this used to be here, but it's been taken down. it's not coming back."
+                }
+            }
+        },
+    )
+    .await
+}
+
+/// The internal error page, using the one authored in content if there is
+/// one. Either way, the error ID is shown at the end.
+#[instrument(skip_all, fields(%error_id))]
+pub async fn internal_error(theme: Theme, authored: Option<&Page>, error_id: &ErrorId) -> Markup {
+    wrappers::base(
+        theme,
+        None,
+        html! {
+            main class="error" {
+                @if let Some(page) = authored {
+                    (PreEscaped(&page.html_content))
+                } @else {
+                    h1 {
+                        "internal server error"
+                    }
+
+                    p {
+                        "wtf, you broke it?! stop doing that."
+                    }
+                }
+
+                p {
+                    "if you want to tell me about it, the error ID is "
+                    code { (error_id) }
+                    "."
                 }
             }
         },
@@ -2,8 +2,9 @@ use maud::{html, Markup};
 
 use crate::state::Theme;
 
-pub async fn head(theme: Theme) -> Markup {
+pub async fn head(theme: Theme, canonical_url: Option<String>) -> Markup {
     let theme_header = theme.theme_header();
+    let stylesheet_integrity = theme.stylesheet_integrity();
     html! {
         head {
             meta charset="utf-8";
@@ -14,7 +15,10 @@ pub async fn head(theme: Theme) -> Markup {
             link rel="preload" href="/static/lora-italic.woff2" as="font" type="font/woff2" crossorigin;
             link rel="preload" href="/static/lora-600.woff2" as="font" type="font/woff2" crossorigin;
 
-            link rel="stylesheet" href="/style.css" type="text/css";
+            link rel="stylesheet" href="/style.css" type="text/css" integrity=(stylesheet_integrity);
+            @if let Some(canonical_url) = canonical_url {
+                link rel="canonical" href=(canonical_url);
+            }
 
             title { "saffi, wtf?!" }
             style {
@@ -2,11 +2,11 @@ use maud::{html, Markup, DOCTYPE};
 
 use crate::{state::Theme, templates::partials};
 
-pub async fn base(theme: Theme, content: Markup) -> Markup {
+pub async fn base(theme: Theme, canonical_url: Option<String>, content: Markup) -> Markup {
     html! {
         (DOCTYPE)
         html lang="en-GB" {
-            (partials::head(theme).await)
+            (partials::head(theme, canonical_url).await)
             body {
                 header {
                     h1 class="sitetitle" {
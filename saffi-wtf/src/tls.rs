@@ -0,0 +1,183 @@
+use std::{
+    io,
+    sync::{Arc, RwLock},
+    time::{Duration, SystemTime},
+};
+
+use axum::{
+    body::Body,
+    extract::State,
+    http::{header, uri::Authority, Request, StatusCode},
+    response::{IntoResponse, Response},
+    Router,
+};
+use camino::Utf8PathBuf;
+use thiserror::Error;
+use tokio::{fs, time};
+use tokio_rustls::{
+    rustls::{Error as RustlsError, ServerConfig},
+    TlsAcceptor,
+};
+use tracing::{info, warn};
+
+use crate::layers::permanent_redirect;
+
+/// How often the certificate and key files are checked for changes.
+const RELOAD_INTERVAL: Duration = Duration::from_secs(10);
+
+/// A TLS certificate and key loaded from PEM files, which can be swapped out
+/// for new ones without restarting the server.
+#[derive(Clone, Debug)]
+pub struct TlsConfig {
+    cert_path: Utf8PathBuf,
+    key_path: Utf8PathBuf,
+    current: Arc<RwLock<Arc<ServerConfig>>>,
+}
+
+impl TlsConfig {
+    pub async fn load(cert_path: Utf8PathBuf, key_path: Utf8PathBuf) -> Result<Self, LoadTlsError> {
+        let server_config = load_server_config(&cert_path, &key_path).await?;
+        info!(cert = %cert_path, key = %key_path, "loaded TLS certificate");
+
+        Ok(Self {
+            cert_path,
+            key_path,
+            current: Arc::new(RwLock::new(Arc::new(server_config))),
+        })
+    }
+
+    /// Returns an acceptor for new connections that uses the current
+    /// certificate.
+    pub fn acceptor(&self) -> TlsAcceptor {
+        let current = self.current.read().expect("lock should not be poisoned");
+        TlsAcceptor::from(Arc::clone(&current))
+    }
+
+    /// Watches the certificate and key files, reloading them whenever either
+    /// one changes, e.g. when a certificate is renewed.
+    ///
+    /// If the new files can't be loaded, the old certificate stays in use.
+    /// Never returns, so should be spawned as its own task.
+    pub async fn reload_on_change(self) {
+        let mut last_modified = self.last_modified().await;
+        let mut interval = time::interval(RELOAD_INTERVAL);
+
+        loop {
+            interval.tick().await;
+
+            let modified = self.last_modified().await;
+            if modified == last_modified {
+                continue;
+            }
+            last_modified = modified;
+
+            match load_server_config(&self.cert_path, &self.key_path).await {
+                Ok(server_config) => {
+                    *self.current.write().expect("lock should not be poisoned") =
+                        Arc::new(server_config);
+                    info!(cert = %self.cert_path, key = %self.key_path, "reloaded TLS certificate");
+                }
+                Err(error) => {
+                    warn!(%error, "failed to reload TLS certificate, keeping the old one");
+                }
+            }
+        }
+    }
+
+    async fn last_modified(&self) -> (Option<SystemTime>, Option<SystemTime>) {
+        let modified = |path| async move {
+            fs::metadata(path)
+                .await
+                .and_then(|metadata| metadata.modified())
+                .ok()
+        };
+        (
+            modified(&self.cert_path).await,
+            modified(&self.key_path).await,
+        )
+    }
+}
+
+/// Builds an app for a plain HTTP listener, which redirects every request to
+/// the same URL over HTTPS on the given port.
+pub fn redirect_to_https_app(https_port: u16) -> Router {
+    Router::new()
+        .fallback(redirect_to_https)
+        .with_state(https_port)
+}
+
+async fn redirect_to_https(State(https_port): State<u16>, request: Request<Body>) -> Response {
+    let Some(authority) = request
+        .headers()
+        .get(header::HOST)
+        .and_then(|host| host.to_str().ok())
+        .and_then(|host| host.parse::<Authority>().ok())
+    else {
+        warn!(route = %request.uri(), "plain HTTP request has no valid host to redirect to");
+        return StatusCode::BAD_REQUEST.into_response();
+    };
+
+    let host = authority.host();
+    let path_and_query = request
+        .uri()
+        .path_and_query()
+        .map_or("/", |path_and_query| path_and_query.as_str());
+    let location = if https_port == 443 {
+        format!("https://{host}{path_and_query}")
+    } else {
+        format!("https://{host}:{https_port}{path_and_query}")
+    };
+
+    info!(route = %request.uri(), %location, "redirecting request to HTTPS");
+    permanent_redirect(request.method(), location)
+}
+
+async fn load_server_config(
+    cert_path: &Utf8PathBuf,
+    key_path: &Utf8PathBuf,
+) -> Result<ServerConfig, LoadTlsError> {
+    use LoadTlsError::*;
+
+    let raw_certs = fs::read(cert_path)
+        .await
+        .map_err(|err| ReadCert(cert_path.clone(), err))?;
+    let certs = rustls_pemfile::certs(&mut raw_certs.as_slice())
+        .collect::<Result<Vec<_>, _>>()
+        .map_err(|err| ParseCert(cert_path.clone(), err))?;
+
+    let raw_key = fs::read(key_path)
+        .await
+        .map_err(|err| ReadKey(key_path.clone(), err))?;
+    let key = rustls_pemfile::private_key(&mut raw_key.as_slice())
+        .map_err(|err| ParseKey(key_path.clone(), err))?
+        .ok_or_else(|| NoPrivateKey(key_path.clone()))?;
+
+    let mut server_config = ServerConfig::builder()
+        .with_no_client_auth()
+        .with_single_cert(certs, key)?;
+    // Offer HTTP/2, falling back to HTTP/1.1 for clients that don't support it.
+    server_config.alpn_protocols = vec![b"h2".to_vec(), b"http/1.1".to_vec()];
+
+    Ok(server_config)
+}
+
+#[derive(Error, Debug)]
+pub enum LoadTlsError {
+    #[error("failed to read TLS certificate file {0}: {1}")]
+    ReadCert(Utf8PathBuf, #[source] io::Error),
+
+    #[error("failed to parse TLS certificate file {0}: {1}")]
+    ParseCert(Utf8PathBuf, #[source] io::Error),
+
+    #[error("failed to read TLS key file {0}: {1}")]
+    ReadKey(Utf8PathBuf, #[source] io::Error),
+
+    #[error("failed to parse TLS key file {0}: {1}")]
+    ParseKey(Utf8PathBuf, #[source] io::Error),
+
+    #[error("TLS key file does not contain a private key: {0}")]
+    NoPrivateKey(Utf8PathBuf),
+
+    #[error("invalid TLS certificate or key: {0}")]
+    InvalidConfig(#[from] RustlsError),
+}
@@ -0,0 +1,150 @@
+use std::{
+    collections::HashMap,
+    convert::Infallible,
+    io,
+    sync::Arc,
+    task::{Context, Poll},
+    time::Duration,
+};
+
+use axum::{
+    body::Body,
+    http::{
+        uri::{Authority, InvalidUri},
+        Request, Uri,
+    },
+    response::Response,
+    routing::future::RouteFuture,
+};
+use camino::{Utf8Path, Utf8PathBuf};
+use serde::Deserialize;
+use thiserror::Error;
+use tokio::fs;
+use tower::Service;
+
+use crate::{layers::request_host, site::Site, state::Config, Args};
+
+/// The config file for an extra site, served alongside the default one for
+/// requests to any of its hosts.
+///
+/// Paths are relative to the working directory, like the ones given as
+/// arguments. Anything not configured here (like the syntaxes and security
+/// headers) is shared with the default site.
+#[derive(Debug, Deserialize)]
+pub struct SiteFile {
+    hosts: Vec<String>,
+    #[serde(default)]
+    drafts: bool,
+    content_path: Utf8PathBuf,
+    static_path: Utf8PathBuf,
+    themes_path: Utf8PathBuf,
+    #[serde(default)]
+    image_cache_path: Option<Utf8PathBuf>,
+    #[serde(default)]
+    canonical_url: Option<String>,
+}
+
+impl SiteFile {
+    pub async fn load(path: &Utf8Path) -> Result<Self, LoadSiteError> {
+        use LoadSiteError::*;
+
+        let raw_site = fs::read_to_string(path)
+            .await
+            .map_err(|err| ReadSiteFile(path.to_owned(), err))?;
+        toml::from_str(&raw_site).map_err(|err| ParseSiteFile(path.to_owned(), err))
+    }
+
+    /// Turns this into the config for a site, along with the hosts it should
+    /// be served for.
+    pub fn into_config(self, args: &Args) -> Result<(Vec<String>, Config), LoadSiteError> {
+        let canonical_url = self
+            .canonical_url
+            .map(|url| {
+                url.parse::<Uri>()
+                    .map_err(|err| LoadSiteError::InvalidCanonicalUrl(url, err))
+            })
+            .transpose()?;
+
+        let config = Config {
+            drafts: self.drafts,
+            content_path: self.content_path,
+            static_path: self.static_path,
+            themes_path: self.themes_path,
+            syntaxes_path: args.syntaxes_path.clone(),
+            image_cache_path: self.image_cache_path,
+            canonical_url,
+            security: args.security.clone(),
+            request_timeout: Duration::from_secs(args.request_timeout),
+        };
+
+        Ok((self.hosts, config))
+    }
+}
+
+#[derive(Error, Debug)]
+pub enum LoadSiteError {
+    #[error("failed to read site config {0}: {1}")]
+    ReadSiteFile(Utf8PathBuf, #[source] io::Error),
+
+    #[error("failed to parse site config {0}: {1}")]
+    ParseSiteFile(Utf8PathBuf, #[source] toml::de::Error),
+
+    #[error("site has an invalid canonical URL {0}: {1}")]
+    InvalidCanonicalUrl(String, #[source] InvalidUri),
+
+    #[error("more than one site is configured for host: {0}")]
+    DuplicateHost(String),
+}
+
+/// Dispatches requests to the site they're for, by host, falling back to the
+/// default site for any host that isn't configured.
+#[derive(Clone)]
+pub struct VirtualHosts {
+    default: Site,
+    by_host: Arc<HashMap<String, Site>>,
+}
+
+impl VirtualHosts {
+    pub fn new(default: Site) -> Self {
+        Self {
+            default,
+            by_host: Arc::default(),
+        }
+    }
+
+    /// Serves a site for requests to any of the given hosts.
+    ///
+    /// Hosts are matched without their port, and case-insensitively.
+    pub fn add(&mut self, hosts: &[String], site: Site) -> Result<(), LoadSiteError> {
+        let by_host = Arc::make_mut(&mut self.by_host);
+        for host in hosts {
+            let host = host.to_ascii_lowercase();
+            if by_host.insert(host.clone(), site.clone()).is_some() {
+                return Err(LoadSiteError::DuplicateHost(host));
+            }
+        }
+
+        Ok(())
+    }
+
+    fn site_for(&self, request: &Request<Body>) -> &Site {
+        request_host(request)
+            .and_then(|host| host.parse::<Authority>().ok())
+            .and_then(|authority| self.by_host.get(&authority.host().to_ascii_lowercase()))
+            .unwrap_or(&self.default)
+    }
+}
+
+impl Service<Request<Body>> for VirtualHosts {
+    type Response = Response;
+    type Error = Infallible;
+    type Future = RouteFuture<Infallible>;
+
+    fn poll_ready(&mut self, _cx: &mut Context<'_>) -> Poll<Result<(), Self::Error>> {
+        Poll::Ready(Ok(()))
+    }
+
+    fn call(&mut self, request: Request<Body>) -> Self::Future {
+        self.site_for(&request).clone().call(request)
+    }
+}
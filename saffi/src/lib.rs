@@ -1,27 +1,23 @@
+use std::future::Future;
+
 use cfg_if::cfg_if;
 use tokio::signal;
 use tracing::info;
-use tracing_subscriber::{fmt, layer::SubscriberExt, util::SubscriberInitExt, EnvFilter};
 
 mod result_option_exts;
+mod shutdown;
+mod telemetry;
 
 pub use result_option_exts::{OptionExt, ResultExt};
+pub use shutdown::{Shutdown, ShutdownToken};
+pub use telemetry::{
+    init_tracing, shutdown_tracing, InitTracingError, LogFormat, OtlpProtocol, TracingArgs,
+};
 
-pub fn init_tracing() {
-    #[cfg(debug_assertions)]
-    let fmt_layer = fmt::layer().with_timer(fmt::time::uptime());
-    #[cfg(not(debug_assertions))]
-    let fmt_layer = fmt::layer();
-
-    tracing_subscriber::registry()
-        .with(
-            EnvFilter::try_from_default_env()
-                .unwrap_or_else(|_| EnvFilter::new("otel::tracing=trace,info")),
-        )
-        .with(fmt_layer)
-        .init();
-}
-
+/// Waits for ctrl-c, or a termination signal on Unix.
+///
+/// This only waits for the signal; [`Shutdown`] takes care of actually
+/// shutting down once it arrives.
 pub async fn graceful_shutdown() {
     // A future that will listen for the ctrl-c input from a terminal.
     let ctrl_c = async {
@@ -57,6 +53,58 @@ pub async fn graceful_shutdown() {
         },
         _ = terminate => info!("termination signal received, starting graceful shutdown"),
     }
+}
 
-    info!("finished shutting down; see you soon!");
+/// Calls `reload` every time a hangup signal (SIGHUP) is received, which is
+/// the conventional way to ask a server to reload its config. Never returns.
+///
+/// Non-Unix platforms don't have hangup signals, so this just waits forever.
+pub async fn on_hangup<F, Fut>(reload: F)
+where
+    F: FnMut() -> Fut,
+    Fut: Future<Output = ()>,
+{
+    cfg_if! {
+        if #[cfg(unix)] {
+            on_signal(signal::unix::SignalKind::hangup(), "hangup", "reloading", reload).await;
+        } else {
+            let _ = reload;
+            std::future::pending::<()>().await;
+        }
+    }
+}
+
+/// Calls `upgrade` every time a SIGUSR2 signal is received, which (like in
+/// nginx) asks a server to hand over to a new copy of its binary. Never
+/// returns.
+///
+/// Non-Unix platforms don't have user-defined signals, so this just waits
+/// forever.
+pub async fn on_upgrade_signal<F, Fut>(upgrade: F)
+where
+    F: FnMut() -> Fut,
+    Fut: Future<Output = ()>,
+{
+    cfg_if! {
+        if #[cfg(unix)] {
+            let kind = signal::unix::SignalKind::user_defined2();
+            on_signal(kind, "upgrade", "starting new process", upgrade).await;
+        } else {
+            let _ = upgrade;
+            std::future::pending::<()>().await;
+        }
+    }
+}
+
+#[cfg(unix)]
+async fn on_signal<F, Fut>(kind: signal::unix::SignalKind, name: &str, action: &str, mut handle: F)
+where
+    F: FnMut() -> Fut,
+    Fut: Future<Output = ()>,
+{
+    let mut signals = signal::unix::signal(kind).expect("should be able to install signal handler");
+    while signals.recv().await.is_some() {
+        info!("{name} signal received, {action}");
+        handle().await;
+    }
 }
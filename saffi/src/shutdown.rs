@@ -0,0 +1,122 @@
+use std::{
+    future::Future,
+    pin::Pin,
+    sync::{Arc, Mutex},
+    time::Duration,
+};
+
+use tokio::{sync::watch, time};
+use tracing::{info, warn};
+
+use crate::graceful_shutdown;
+
+/// How long each shutdown hook gets to finish before it's abandoned.
+const HOOK_TIMEOUT: Duration = Duration::from_secs(10);
+
+type Hook = Box<dyn FnOnce() -> Pin<Box<dyn Future<Output = ()> + Send>> + Send>;
+
+/// Coordinates shutting down: telling everything that's serving to stop,
+/// waiting (for a limited time) for them to drain, and then running cleanup
+/// hooks.
+#[derive(Clone)]
+pub struct Shutdown {
+    drain_timeout: Duration,
+    requested_tx: Arc<watch::Sender<bool>>,
+    hooks: Arc<Mutex<Vec<(&'static str, Hook)>>>,
+}
+
+impl Shutdown {
+    pub fn new(drain_timeout: Duration) -> Self {
+        Self {
+            drain_timeout,
+            requested_tx: Arc::new(watch::channel(false).0),
+            hooks: Arc::default(),
+        }
+    }
+
+    /// Returns a token that resolves once shutdown has been requested.
+    pub fn token(&self) -> ShutdownToken {
+        ShutdownToken(self.requested_tx.subscribe())
+    }
+
+    /// Requests a shutdown, as if a termination signal had been received.
+    pub fn request(&self) {
+        self.requested_tx.send_replace(true);
+    }
+
+    /// Registers a hook to run once everything has drained (or the drain
+    /// timeout has passed).
+    ///
+    /// Hooks run one at a time, in the reverse of the order they were
+    /// registered in, so anything registered early (like flushing telemetry)
+    /// runs last.
+    pub fn on_shutdown<F, Fut>(&self, name: &'static str, hook: F)
+    where
+        F: FnOnce() -> Fut + Send + 'static,
+        Fut: Future<Output = ()> + Send + 'static,
+    {
+        self.hooks
+            .lock()
+            .expect("lock should not be poisoned")
+            .push((name, Box::new(move || Box::pin(hook()))));
+    }
+
+    /// Drives `serving` until a termination signal is received or shutdown
+    /// is requested, then waits up to the drain timeout for it to finish
+    /// before running the shutdown hooks.
+    ///
+    /// Anything in `serving` should be watching a [`ShutdownToken`] to know
+    /// when to stop accepting new work.
+    pub async fn run(self, serving: impl Future<Output = ()>) {
+        tokio::pin!(serving);
+
+        let token = self.token();
+        let drained = tokio::select! {
+            // Serving can finish because shutdown was requested, in which case
+            // it still needs to be treated as a shutdown.
+            biased;
+            () = token.requested() => {
+                info!("shutdown requested, starting graceful shutdown");
+                false
+            }
+            () = graceful_shutdown() => false,
+            () = &mut serving => true,
+        };
+
+        if !drained {
+            self.request();
+            match time::timeout(self.drain_timeout, serving).await {
+                Ok(()) => info!("finished draining"),
+                Err(_) => warn!(
+                    timeout = ?self.drain_timeout,
+                    "gave up waiting for everything to drain"
+                ),
+            }
+        }
+
+        let hooks = std::mem::take(&mut *self.hooks.lock().expect("lock should not be poisoned"));
+        for (name, hook) in hooks.into_iter().rev() {
+            info!(hook = name, "running shutdown hook");
+            if time::timeout(HOOK_TIMEOUT, hook()).await.is_err() {
+                warn!(hook = name, timeout = ?HOOK_TIMEOUT, "shutdown hook timed out");
+            }
+        }
+
+        info!("finished shutting down; see you soon!");
+    }
+}
+
+/// A handle for finding out when shutdown has been requested.
+#[derive(Clone, Debug)]
+pub struct ShutdownToken(watch::Receiver<bool>);
+
+impl ShutdownToken {
+    /// Resolves once shutdown has been requested, straight away if it
+    /// already has been.
+    pub async fn requested(&self) {
+        let mut requested_rx = self.0.clone();
+        // The sender only goes away once the coordinator has, which means
+        // it's time to shut down anyway.
+        let _ = requested_rx.wait_for(|requested| *requested).await;
+    }
+}
@@ -0,0 +1,249 @@
+use std::{fs::OpenOptions, io, path::PathBuf, sync::Arc};
+
+use opentelemetry::{trace::TraceError, KeyValue};
+use opentelemetry_otlp::{SpanExporterBuilder, WithExportConfig};
+use opentelemetry_sdk::{
+    runtime,
+    trace::{self, Sampler, Tracer},
+    Resource,
+};
+use thiserror::Error;
+use tracing::Subscriber;
+use tracing_subscriber::{
+    fmt::{self, MakeWriter},
+    layer::SubscriberExt,
+    registry::LookupSpan,
+    util::{SubscriberInitExt, TryInitError},
+    EnvFilter, Layer,
+};
+
+/// Options for logging, and for exporting traces over OTLP.
+#[derive(clap::Args, Clone, Debug)]
+pub struct TracingArgs {
+    /// Where to export traces to, like `http://localhost:4318`. Traces are
+    /// only exported if this is set.
+    #[arg(long, env = "OTEL_EXPORTER_OTLP_ENDPOINT")]
+    pub otlp_endpoint: Option<String>,
+
+    #[arg(long, env = "OTEL_EXPORTER_OTLP_PROTOCOL", value_enum, default_value_t)]
+    pub otlp_protocol: OtlpProtocol,
+
+    /// The name traces are exported under. Defaults to the name of the binary.
+    #[arg(long, env = "OTEL_SERVICE_NAME")]
+    pub service_name: Option<String>,
+
+    /// The fraction of traces to export, from 0 to 1. Traces continued from
+    /// an incoming request are exported if the caller sampled them.
+    #[arg(long, env = "TRACE_SAMPLE_RATIO", default_value_t = 1.0)]
+    pub trace_sample_ratio: f64,
+
+    #[arg(long, env = "LOG_FORMAT", value_enum, default_value_t)]
+    pub log_format: LogFormat,
+
+    /// A file to append logs to, as well as writing them to stdout.
+    #[arg(long, env = "LOG_FILE")]
+    pub log_file: Option<PathBuf>,
+}
+
+#[derive(clap::ValueEnum, Clone, Copy, Debug, Default)]
+pub enum OtlpProtocol {
+    Grpc,
+    #[default]
+    #[value(name = "http/protobuf")]
+    HttpProtobuf,
+}
+
+#[derive(clap::ValueEnum, Clone, Copy, Debug, Default)]
+pub enum LogFormat {
+    /// Human-readable lines.
+    #[default]
+    Pretty,
+    /// One JSON object per line, including the fields of every open span.
+    Json,
+}
+
+#[derive(Error, Debug)]
+pub enum InitTracingError {
+    #[error("failed to open log file {0}: {1}")]
+    OpenLogFile(PathBuf, #[source] io::Error),
+
+    #[error("failed to set up trace propagation: {0}")]
+    Propagator(#[source] TraceError),
+
+    #[error("failed to set up trace exporter: {0}")]
+    Exporter(#[source] TraceError),
+
+    #[error("failed to install tracing subscriber: {0}")]
+    Subscriber(#[from] TryInitError),
+}
+
+/// Sets up logging to stdout (and optionally a file), and exporting traces
+/// over OTLP if an endpoint is configured.
+///
+/// This must be called from within a Tokio runtime, which exported spans are
+/// sent from in batches. Call [`shutdown_tracing()`] before exiting to send
+/// the last batch.
+pub fn init_tracing(
+    args: &TracingArgs,
+    default_service_name: &str,
+) -> Result<(), InitTracingError> {
+    use InitTracingError::*;
+
+    let log_file = args
+        .log_file
+        .as_ref()
+        .map(|path| {
+            OpenOptions::new()
+                .create(true)
+                .append(true)
+                .open(path)
+                .map(Arc::new)
+                .map_err(|err| OpenLogFile(path.clone(), err))
+        })
+        .transpose()?;
+
+    let otel_layer = args
+        .otlp_endpoint
+        .as_deref()
+        .map(|endpoint| {
+            init_tracing_opentelemetry::init_propagator().map_err(Propagator)?;
+            let service_name = args.service_name.as_deref().unwrap_or(default_service_name);
+            let tracer = init_tracer(args, endpoint, service_name).map_err(Exporter)?;
+            Ok::<_, InitTracingError>(tracing_opentelemetry::layer().with_tracer(tracer))
+        })
+        .transpose()?;
+
+    tracing_subscriber::registry()
+        .with(
+            EnvFilter::try_from_default_env()
+                .unwrap_or_else(|_| EnvFilter::new("otel::tracing=trace,info")),
+        )
+        .with(fmt_layer(args.log_format, io::stdout, true))
+        .with(log_file.map(|file| fmt_layer(args.log_format, file, false)))
+        .with(otel_layer)
+        .try_init()?;
+
+    Ok(())
+}
+
+/// Exports any spans that haven't been yet, and stops exporting any more.
+pub async fn shutdown_tracing() {
+    // Shutting down the tracer provider blocks until the last batch has been
+    // exported, so it mustn't happen on one of the runtime's worker threads.
+    let _ = tokio::task::spawn_blocking(opentelemetry::global::shutdown_tracer_provider).await;
+}
+
+fn fmt_layer<S, W>(format: LogFormat, writer: W, ansi: bool) -> Box<dyn Layer<S> + Send + Sync>
+where
+    S: Subscriber + for<'span> LookupSpan<'span>,
+    W: for<'writer> MakeWriter<'writer> + Send + Sync + 'static,
+{
+    let layer = fmt::layer().with_writer(writer).with_ansi(ansi);
+    match format {
+        LogFormat::Pretty => {
+            #[cfg(debug_assertions)]
+            let layer = layer.with_timer(fmt::time::uptime());
+            layer.boxed()
+        }
+        LogFormat::Json => layer.json().boxed(),
+    }
+}
+
+fn init_tracer(
+    args: &TracingArgs,
+    endpoint: &str,
+    service_name: &str,
+) -> Result<Tracer, TraceError> {
+    let exporter: SpanExporterBuilder = match args.otlp_protocol {
+        OtlpProtocol::Grpc => opentelemetry_otlp::new_exporter()
+            .tonic()
+            .with_endpoint(endpoint)
+            .into(),
+        OtlpProtocol::HttpProtobuf => opentelemetry_otlp::new_exporter()
+            .http()
+            .with_endpoint(endpoint)
+            .into(),
+    };
+
+    let resource = Resource::default().merge(&Resource::new([KeyValue::new(
+        "service.name",
+        service_name.to_owned(),
+    )]));
+    let sampler = Sampler::ParentBased(Box::new(Sampler::TraceIdRatioBased(
+        args.trace_sample_ratio,
+    )));
+
+    opentelemetry_otlp::new_pipeline()
+        .tracing()
+        .with_exporter(exporter)
+        .with_trace_config(
+            trace::config()
+                .with_resource(resource)
+                .with_sampler(sampler),
+        )
+        .install_batch(runtime::Tokio)
+}
+
+#[cfg(test)]
+mod tests {
+    use axum::{body::Bytes, extract::State, routing::post, Router};
+    use opentelemetry_proto::tonic::collector::trace::v1::ExportTraceServiceRequest;
+    use prost::Message;
+    use tokio::{net::TcpListener, sync::mpsc};
+    use tracing::info_span;
+
+    use super::*;
+
+    /// Starts an OTLP/HTTP collector, returning its endpoint and the names of
+    /// the spans exported to it.
+    async fn start_collector() -> (String, mpsc::UnboundedReceiver<String>) {
+        async fn export(State(spans_tx): State<mpsc::UnboundedSender<String>>, body: Bytes) {
+            let request =
+                ExportTraceServiceRequest::decode(body).expect("export should be valid protobuf");
+            let spans = request
+                .resource_spans
+                .into_iter()
+                .flat_map(|resource_spans| resource_spans.scope_spans)
+                .flat_map(|scope_spans| scope_spans.spans);
+            for span in spans {
+                let _ = spans_tx.send(span.name);
+            }
+        }
+
+        let (spans_tx, spans_rx) = mpsc::unbounded_channel();
+        let app = Router::new()
+            .route("/v1/traces", post(export))
+            .with_state(spans_tx);
+
+        let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let endpoint = format!("http://{}", listener.local_addr().unwrap());
+        tokio::spawn(async move { axum::serve(listener, app).await });
+
+        (endpoint, spans_rx)
+    }
+
+    #[tokio::test(flavor = "multi_thread")]
+    async fn shutting_down_exports_the_last_spans() {
+        let (endpoint, mut spans_rx) = start_collector().await;
+        let args = TracingArgs {
+            otlp_endpoint: Some(endpoint),
+            otlp_protocol: OtlpProtocol::HttpProtobuf,
+            service_name: None,
+            trace_sample_ratio: 1.0,
+            log_format: LogFormat::Pretty,
+            log_file: None,
+        };
+        init_tracing(&args, "www-saffi-test").unwrap();
+
+        // Batches are only sent every few seconds, so this span is only
+        // exported in time if shutting down sends the last batch.
+        info_span!("before_shutdown").in_scope(|| {});
+        shutdown_tracing().await;
+
+        let mut exported = Vec::new();
+        while let Ok(name) = spans_rx.try_recv() {
+            exported.push(name);
+        }
+        assert_eq!(exported, ["before_shutdown"]);
+    }
+}